@@ -1,14 +1,32 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use crate::item_counter::ItemCounter;
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+
+pub mod notation;
 pub mod standard;
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Debug)]
-/// Represents an instance of a symbol found on a die
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "SymbolRepr", into = "SymbolRepr"))]
+/// Represents an instance of a symbol found on a die. Symbols are identified by name alone: the value takes no part in 
+/// equality, hashing or ordering, so each name should be given a single value. With the `serde` feature, a symbol is stored as its name, 
+/// or as its name and value when the value isn't `1`
 pub struct DieSymbol {
-    name: String
+    name: String,
+    value: i64
 }
 impl DieSymbol {
-    /// Creates a new [`DieSymbol`](crate::dice::DieSymbol). Returns an `Err` if input is empty or only whitespace, otherwise returns `Ok`
+    /// Creates a new [`DieSymbol`](crate::dice::DieSymbol) with a value of `1`. Leading and trailing whitespace is trimmed but whitespace inside the name is kept as is, 
+    /// so `"Dark   Elf"` and `"Dark Elf"` are different symbols; use [`new_normalized`](crate::dice::DieSymbol::new_normalized) to treat them as the same. 
+    /// Returns an `Err` if input is empty or only whitespace, otherwise returns `Ok`
     /// 
     /// # Example
     /// ```rust
@@ -23,10 +41,70 @@ impl DieSymbol {
         let trimmed = val.as_ref().trim();
         match trimmed.len() {
             0 => Err("Value cannot be empty".to_string()),
-            _ => Ok(DieSymbol { name: trimmed.to_string() })
+            _ => Ok(DieSymbol { name: trimmed.to_string(), value: 1 })
         }
     }
 
+    /// Creates a [`DieSymbol`](crate::dice::DieSymbol) for each of the names like [`new`](crate::dice::DieSymbol::new), in the same order. 
+    /// Returns an `Err` naming the index of the first name that is empty or only whitespace, otherwise returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::DieSymbol;
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = DieSymbol::new_many(&[ "Sword", "Shield", "Blank" ])?;
+    /// 
+    /// assert_eq!(symbols[1], DieSymbol::new("Shield")?);
+    /// assert!(DieSymbol::new_many(&[ "Sword", " " ]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_many(names: &[&str]) -> Result<Vec<DieSymbol>, String> {
+        names.iter()
+            .enumerate()
+            .map(|(i, name)| DieSymbol::new(name).map_err(|e| format!("Symbol at index {}: {}", i, e)))
+            .collect()
+    }
+
+    /// Creates a new [`DieSymbol`](crate::dice::DieSymbol) like [`new`](crate::dice::DieSymbol::new), but worth `value` rather than `1` 
+    /// when a side's symbols are totalled, such as a face printed with a "5". Returns an `Err` if input is empty or only whitespace, otherwise returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::DieSymbol;
+    /// # fn main() -> Result<(), String> {
+    /// let five = DieSymbol::new_valued("Five", 5)?;
+    /// 
+    /// assert_eq!(five.value(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_valued(val: impl AsRef<str>, value: i64) -> Result<DieSymbol, String> {
+        let mut symbol = DieSymbol::new(val)?;
+        symbol.value = value;
+        Ok(symbol)
+    }
+
+    /// Creates a new [`DieSymbol`](crate::dice::DieSymbol) like [`new`](crate::dice::DieSymbol::new), but also collapses every run of whitespace 
+    /// inside the name to a single space, so `"Dark   Elf"` becomes `"Dark Elf"`. Returns an `Err` if input is empty or only whitespace, otherwise returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::DieSymbol;
+    /// # fn main() -> Result<(), String> {
+    /// let symbol = DieSymbol::new_normalized(" Dark \t  Elf ")?;
+    /// 
+    /// assert_eq!(symbol, DieSymbol::new("Dark Elf")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_normalized(val: impl AsRef<str>) -> Result<DieSymbol, String> {
+        DieSymbol::new(val.as_ref().split_whitespace().collect::<Vec<&str>>().join(" "))
+    }
+
     /// The underlying name value of the [`DieSymbol`](crate::dice::DieSymbol)
     /// 
     /// # Example
@@ -43,12 +121,32 @@ impl DieSymbol {
     pub fn name(&self) -> &String {
         &self.name
     }
+
+    /// The numeric value of the [`DieSymbol`](crate::dice::DieSymbol), which is `1` unless it was created with [`new_valued`](crate::dice::DieSymbol::new_valued)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::DieSymbol;
+    /// # fn main() -> Result<(), String> {
+    /// let symbol = DieSymbol::new("Pip")?;
+    /// 
+    /// assert_eq!(symbol.value(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value(&self) -> i64 {
+        self.value
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// Represents a side of a die and contains a collection of [`DieSymbols`](crate::dice::DieSymbol)
 pub struct DieSide {
-    symbols: Vec<DieSymbol>
+    symbols: Vec<DieSymbol>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    value: Option<i64>
 }
 impl DieSide {
     /// Creates a new [`DieSide`](crate::dice::DieSide) with a collection of [`DieSymbols`](crate::dice::DieSymbol). Input collection may be empty, representing a blank side
@@ -65,7 +163,25 @@ impl DieSide {
     /// # }
     /// ```
     pub fn new(symbols: Vec<DieSymbol>) -> DieSide {
-        DieSide { symbols }
+        DieSide { symbols, value: None }
+    }
+
+    /// Creates a new [`DieSide`](crate::dice::DieSide) with a collection of [`DieSymbols`](crate::dice::DieSymbol) and a numeric value for the side. 
+    /// Input collection may be empty, representing a side which only carries a value
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![DieSymbol::new("Coin")?];
+    /// 
+    /// let side = DieSide::with_value(symbols, 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_value(symbols: Vec<DieSymbol>, value: i64) -> DieSide {
+        DieSide { symbols, value: Some(value) }
     }
 
     /// Returns a slice of all [`DieSymbols`](crate::dice::DieSymbol) on the [`DieSide`](crate::dice::DieSide)
@@ -86,14 +202,115 @@ impl DieSide {
     /// # }
     /// ```
     pub fn symbols(&self) -> &[DieSymbol] {
-        &self.symbols.as_slice()
+        self.symbols.as_slice()
+    }
+
+    /// Returns the numeric value of the [`DieSide`](crate::dice::DieSide), or `None` if it was created without one
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide};
+    /// # fn main() -> Result<(), String> {
+    /// let valued = DieSide::with_value(vec![], -2);
+    /// let plain = DieSide::new(vec![ DieSymbol::new("Pip")? ]);
+    /// 
+    /// assert_eq!(valued.value(), Some(-2));
+    /// assert_eq!(plain.value(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value(&self) -> Option<i64> {
+        self.value
+    }
+
+    /// Returns the sum of the values of the [`DieSymbols`](crate::dice::DieSymbol) on the side, which is the number of symbols unless some were created with 
+    /// [`new_valued`](crate::dice::DieSymbol::new_valued)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide};
+    /// # fn main() -> Result<(), String> {
+    /// let side = DieSide::new(vec![ DieSymbol::new_valued("Five", 5)?, DieSymbol::new("Pip")? ]);
+    /// 
+    /// assert_eq!(side.total_value(), 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_value(&self) -> i64 {
+        self.symbols.iter().map(|s| s.value).sum()
+    }
+
+    /// Returns the number of times the [`DieSymbol`](crate::dice::DieSymbol) appears on the side, or `0` for a blank side
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide};
+    /// # fn main() -> Result<(), String> {
+    /// let pip = DieSymbol::new("Pip")?;
+    /// let side = DieSide::new(vec![ pip.clone(), pip.clone() ]);
+    /// 
+    /// assert_eq!(side.count_of(&pip), 2);
+    /// assert_eq!(DieSide::new(vec![]).count_of(&pip), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_of(&self, symbol: &DieSymbol) -> usize {
+        self.symbols.iter().filter(|s| *s == symbol).count()
+    }
+
+    /// Returns the number of times each [`DieSymbol`](crate::dice::DieSymbol) appears on the side as an [`ItemCounter`](crate::item_counter::ItemCounter), 
+    /// which is empty for a blank side
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide};
+    /// # fn main() -> Result<(), String> {
+    /// let pip = DieSymbol::new("Pip")?;
+    /// let star = DieSymbol::new("Star")?;
+    /// let side = DieSide::new(vec![ pip.clone(), star.clone(), pip.clone() ]);
+    /// 
+    /// let counts = side.symbol_counts();
+    /// 
+    /// assert_eq!(counts.get_count(&pip), 2);
+    /// assert_eq!(counts.distinct_count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symbol_counts(&self) -> ItemCounter<DieSymbol> {
+        ItemCounter::from_slice(&self.symbols)
+    }
+
+    /// Returns `true` if the [`DieSymbol`](crate::dice::DieSymbol) appears on the side at least once, else returns `false`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide};
+    /// # fn main() -> Result<(), String> {
+    /// let pip = DieSymbol::new("Pip")?;
+    /// let side = DieSide::new(vec![ pip.clone() ]);
+    /// 
+    /// assert!(side.contains(&pip));
+    /// assert!(!DieSide::new(vec![]).contains(&pip));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains(&self, symbol: &DieSymbol) -> bool {
+        self.symbols.contains(symbol)
     }
 }
 
 #[derive(Clone, Debug)]
-/// Represents a die containing a collection of all its [`DieSides`](crate::dice::DieSide)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "DieRepr", into = "DieRepr"))]
+/// Represents a die containing a collection of all its [`DieSides`](crate::dice::DieSide). With the `serde` feature, 
+/// a loaded die is checked in the same way as [`Die::new_weighted`](crate::dice::Die::new_weighted), and the weights are only stored when some side isn't weighted `1`
 pub struct Die {
-    sides: Vec<DieSide>
+    sides: Vec<DieSide>,
+    weights: Vec<usize>
 }
 impl Die {
     /// Creates a new instance of a [`Die`](crate::dice::Die) with its [`DieSides`](crate::dice::DieSide). Returns `Err` if input sides has fewer than 2 sides (a coin), else returns `Ok`
@@ -117,8 +334,91 @@ impl Die {
         match sides.len() {
             0 => Err("Die must have at least 2 sides".to_string()),
             1 => Err("Die must have at least 2 sides".to_string()),
-            _ => Ok(Die { sides })
+            _ => Ok(Die { weights: vec![1; sides.len()], sides })
+        }
+    }
+
+    /// Creates a new instance of a weighted [`Die`](crate::dice::Die), where each [`DieSide`](crate::dice::DieSide) is rolled with a probability 
+    /// of its weight over the total weight, such as a loaded die. A fair die has a weight of 1 on every side. 
+    /// Returns `Err` if input sides has fewer than 2 sides or any side has a weight of 0, else returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # fn main() -> Result<(), String> {
+    /// let heads = vec![ DieSymbol::new("Heads")? ];
+    /// let tails = vec![ DieSymbol::new("Tails")? ];
+    /// let sides = vec![ (DieSide::new(heads.clone()), 3), (DieSide::new(tails), 1) ];
+    /// 
+    /// let loaded_coin = Die::new_weighted(sides)?;
+    /// 
+    /// assert_eq!(loaded_coin.average_of(&heads[0]), 0.75);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_weighted(sides: Vec<(DieSide, usize)>) -> Result<Die, String> {
+        if sides.iter().any(|(_, weight)| *weight == 0) {
+            return Err("Die sides must have a nonzero weight".to_string());
+        }
+        let (sides, weights): (Vec<DieSide>, Vec<usize>) = sides.into_iter().unzip();
+        match sides.len() {
+            0 => Err("Die must have at least 2 sides".to_string()),
+            1 => Err("Die must have at least 2 sides".to_string()),
+            _ => Ok(Die { sides, weights })
+        }
+    }
+
+    /// Creates a new instance of a weighted [`Die`](crate::dice::Die) from `(value, weight)` entries, with a [`DieSide`](crate::dice::DieSide) 
+    /// for each value with no [`DieSymbols`](crate::dice::DieSymbol), so the probability of rolling a value is its weight over the total weight. 
+    /// Entries with a weight of 0 are left off the die. Returns `Err` if fewer than 2 entries have a nonzero weight, else returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::Die;
+    /// # fn main() -> Result<(), String> {
+    /// let die = Die::from_weighted_values(&[ (1, 3), (5, 1) ])?;
+    /// 
+    /// assert_eq!(die.expected_value(), 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_weighted_values(entries: &[(i64, usize)]) -> Result<Die, String> {
+        if entries.iter().filter(|(_, weight)| *weight > 0).count() < 2 {
+            return Err("Weighted die must have at least 2 values with a nonzero weight".to_string());
         }
+        let sides = 
+            entries.iter()
+            .filter(|(_, weight)| *weight > 0)
+            .map(|(value, weight)| (DieSide::with_value(vec![], *value), *weight))
+            .collect();
+        Die::new_weighted(sides)
+    }
+
+    /// Creates a new instance of a [`Die`](crate::dice::Die) with a [`DieSide`](crate::dice::DieSide) for each face value, where each side shows a 
+    /// [`valued`](crate::dice::DieSymbol::new_valued) [`DieSymbol`](crate::dice::DieSymbol) named after its value. Faces sharing a value show the same symbol, 
+    /// so repeating a value makes it more likely. Returns `Err` if fewer than 2 faces are provided, else returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, Die};
+    /// # fn main() -> Result<(), String> {
+    /// let die = Die::from_faces(&[ 1, 2, 2, 3 ])?;
+    /// let two = DieSymbol::new_valued("2", 2)?;
+    /// 
+    /// assert_eq!(die.average_of(&two), 0.5);
+    /// assert_eq!(die.expected_value(), 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_faces(values: &[i64]) -> Result<Die, String> {
+        let sides = 
+            values.iter()
+            .map(|value| DieSymbol::new_valued(value.to_string(), *value).map(|symbol| DieSide::new(vec![ symbol ])))
+            .collect::<Result<Vec<DieSide>, String>>()?;
+        Die::new(sides)
     }
 
     /// Returns a slice of all [`DieSides`](crate::dice::DieSide) in the [`Die`](crate::dice::Die)
@@ -145,6 +445,98 @@ impl Die {
         self.sides.as_slice()
     }
 
+    /// Returns a slice of the weight of each [`DieSide`](crate::dice::DieSide), in the same order as [`sides`](crate::dice::Die::sides)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let loaded = Die::new_weighted(vec![ (DieSide::new(vec![]), 1), (DieSide::new(vec![ standard::pip() ]), 2) ])?;
+    /// 
+    /// assert_eq!(loaded.weights(), &[1, 2]);
+    /// assert_eq!(standard::d4().weights(), &[1, 1, 1, 1]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn weights(&self) -> &[usize] {
+        self.weights.as_slice()
+    }
+
+    /// Returns the sum of the weights of all [`DieSides`](crate::dice::DieSide), which is the number of sides for a fair die
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let loaded = Die::new_weighted(vec![ (DieSide::new(vec![]), 1), (DieSide::new(vec![ standard::pip() ]), 2) ])?;
+    /// 
+    /// assert_eq!(loaded.total_weight(), 3);
+    /// assert_eq!(standard::d4().total_weight(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_weight(&self) -> usize {
+        self.weights.iter().sum()
+    }
+
+    pub(crate) fn weighted_sides(&self) -> impl Iterator<Item = (&DieSide, usize)> + Clone {
+        self.sides.iter().zip(self.weights.iter().copied())
+    }
+
+    /// Returns the [`DieSide`](crate::dice::DieSide) at the provided index, or `None` if the index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # fn main() -> Result<(), String> {
+    /// # let heads = vec![ DieSymbol::new("Heads")? ];
+    /// # let heads_side = DieSide::new(heads);
+    /// # let tails = vec![ DieSymbol::new("Tails")? ];
+    /// # let tails_side = DieSide::new(tails);
+    /// let sides = vec![ heads_side, tails_side ];
+    /// let coin = Die::new(sides)?;
+    /// 
+    /// assert_eq!(coin.side(1).unwrap().symbols()[0].name(), "Tails");
+    /// assert!(coin.side(2).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn side(&self, index: usize) -> Option<&DieSide> {
+        self.sides.get(index)
+    }
+
+    /// Rolls the [`Die`](crate::dice::Die) once, returning a random [`DieSide`](crate::dice::DieSide) chosen according to the side weights
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let d6 = standard::d6();
+    /// 
+    /// let side = d6.roll(&mut rand::thread_rng());
+    /// 
+    /// assert!((1..=6).contains(&side.symbols().len()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> &DieSide {
+        let mut remaining = rng.gen_range(0..self.total_weight());
+        for (side, weight) in self.weighted_sides() {
+            if remaining < weight {
+                return side;
+            }
+            remaining -= weight;
+        }
+        &self.sides[self.sides.len() - 1]
+    }
+
     /// Returns a distinct collection of all [`DieSymbols`](crate::dice::DieSymbol) represented on all [`DieSides`](crate::dice::DieSide) of the [`Die`](crate::dice::Die) as a `Vec`
     /// 
     /// # Example
@@ -169,8 +561,7 @@ impl Die {
         let mut unique = Vec::new();
         for symbol in
                 self.sides.iter()
-                .map(|s| s.symbols())
-                .flatten()
+                .flat_map(|s| s.symbols())
                 .cloned()
                 .collect::<Vec<DieSymbol>>() {
             if !unique.contains(&symbol) {
@@ -180,7 +571,47 @@ impl Die {
         unique
     }
 
-    /// Returns the average amount of times a [`DieSymbol`] will appear on a [`Die`] when rolled as an `f64`.
+    /// Returns the most times a [`DieSymbol`] appears on any single side of the [`Die`]
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard::{ d6, pip };
+    /// # fn main() -> Result<(), String> {
+    /// let d6 = d6();
+    /// 
+    /// assert_eq!(d6.max_of(&pip()), 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn max_of(&self, symbol: &DieSymbol) -> usize {
+        self.sides.iter()
+            .map(|s| s.count_of(symbol))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the fewest times a [`DieSymbol`] appears on any single side of the [`Die`]
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard::{ d6, pip };
+    /// # fn main() -> Result<(), String> {
+    /// let d6 = d6();
+    /// 
+    /// assert_eq!(d6.min_of(&pip()), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn min_of(&self, symbol: &DieSymbol) -> usize {
+        self.sides.iter()
+            .map(|s| s.count_of(symbol))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Returns the average amount of times a [`DieSymbol`] will appear on a [`Die`] when rolled as an `f64`, taking the side weights into account.
     /// 
     /// # Example
     /// ```rust
@@ -197,12 +628,535 @@ impl Die {
     /// # }
     /// ```
     pub fn average_of(&self, symbol: &DieSymbol) -> f64 {
-        let sides = self.sides.len() as f64;
-        let symbol_count = self.sides.iter()
-            .map(|s| s.symbols())
-            .flatten()
-            .filter(|&s| *s == *symbol)
-            .count() as f64;
-        symbol_count / sides
+        let symbol_count: usize = self.weighted_sides()
+            .map(|(side, weight)| side.count_of(symbol) * weight)
+            .sum();
+        symbol_count as f64 / self.total_weight() as f64
+    }
+
+    /// Returns the variance of the amount of times a [`DieSymbol`] will appear on a [`Die`] when rolled as an `f64`, taking the side weights into account.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die };
+    /// # use art_dice::dice::standard::{ d6 };
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = d6();
+    /// # let pip = d6.unique_symbols().first().unwrap().clone();
+    /// let variance = d6.variance_of(&pip);
+    /// 
+    /// assert!((variance - 35.0 / 12.0).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn variance_of(&self, symbol: &DieSymbol) -> f64 {
+        let average = self.average_of(symbol);
+        let squared_deviations: f64 = self.weighted_sides()
+            .map(|(side, weight)| (side.count_of(symbol) as f64 - average).powi(2) * weight as f64)
+            .sum();
+        squared_deviations / self.total_weight() as f64
+    }
+
+    /// Returns the standard deviation of the amount of times a [`DieSymbol`] will appear on a [`Die`] when rolled as an `f64`, 
+    /// the square root of the [`variance_of`](crate::dice::Die::variance_of) the symbol.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die };
+    /// # use art_dice::dice::standard::{ d4 };
+    /// # fn main() -> Result<(), String> {
+    /// # let d4 = d4();
+    /// # let pip = d4.unique_symbols().first().unwrap().clone();
+    /// let std_dev = d4.std_dev_of(&pip);
+    /// 
+    /// assert_eq!(std_dev, 1.25_f64.sqrt());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn std_dev_of(&self, symbol: &DieSymbol) -> f64 {
+        self.variance_of(symbol).sqrt()
+    }
+
+    /// Returns the average value of the [`Die`](crate::dice::Die) when rolled as an `f64`. 
+    /// Each [`DieSide`](crate::dice::DieSide) contributes its [`value`](crate::dice::DieSide::value), 
+    /// or its [`total_value`](crate::dice::DieSide::total_value) when it has no value.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # fn main() -> Result<(), String> {
+    /// let sides = vec![ DieSide::with_value(vec![], -2), DieSide::with_value(vec![], 0), DieSide::with_value(vec![], 5) ];
+    /// let die = Die::new(sides)?;
+    /// 
+    /// let expected = die.expected_value();
+    /// 
+    /// assert_eq!(expected, 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn expected_value(&self) -> f64 {
+        let value_sum: i64 = 
+            self.weighted_sides()
+            .map(|(s, weight)| s.value().unwrap_or_else(|| s.total_value()) * weight as i64)
+            .sum();
+        (value_sum as f64) / self.total_weight() as f64
+    }
+
+    /// Returns the probability that two rolls of the [`Die`](crate::dice::Die) show the same number of the [`DieSymbol`](crate::dice::DieSymbol) as an `f64`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die };
+    /// # use art_dice::dice::standard::{ d6 };
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = d6();
+    /// # let pip = d6.unique_symbols().first().unwrap().clone();
+    /// let tie = d6.self_tie_probability(&pip);
+    /// 
+    /// assert!((tie - 1.0 / 6.0).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn self_tie_probability(&self, symbol: &DieSymbol) -> f64 {
+        let total_weight = self.total_weight() as f64;
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for (side, weight) in self.weighted_sides() {
+            let count = side.count_of(symbol);
+            *counts.entry(count).or_insert(0) += weight;
+        }
+        counts.values()
+            .map(|&n| (n as f64 / total_weight).powi(2))
+            .sum()
+    }
+
+    /// Creates a new compound [`Die`](crate::dice::Die) with a [`DieSide`](crate::dice::DieSide) for every pairing of this die's sides with the other die's sides, 
+    /// each carrying the [`DieSymbols`](crate::dice::DieSymbol) of both and weighted by the product of both sides' weights. 
+    /// If either side of a pairing has a value, the new side's value is the sum of both sides' values, 
+    /// using the [`total_value`](crate::dice::DieSide::total_value) for a side without one. Returns `Err` if the result has fewer than 2 sides, else returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let d4 = standard::d4();
+    /// let d6 = standard::d6();
+    /// 
+    /// let compound = d4.product(&d6)?;
+    /// 
+    /// assert_eq!(compound.sides().len(), 24);
+    /// assert_eq!(compound.average_of(&standard::pip()), 6.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn product(&self, other: &Die) -> Result<Die, String> {
+        let mut sides = Vec::with_capacity(self.sides.len() * other.sides.len());
+        for (first, first_weight) in self.weighted_sides() {
+            for (second, second_weight) in other.weighted_sides() {
+                let symbols = first.symbols.iter().chain(second.symbols.iter()).cloned().collect();
+                let value = match (first.value, second.value) {
+                    (None, None) => None,
+                    (a, b) => Some(
+                        a.unwrap_or_else(|| first.total_value()) + 
+                        b.unwrap_or_else(|| second.total_value()))
+                };
+                sides.push((DieSide { symbols, value }, first_weight * second_weight));
+            }
+        }
+        Die::new_weighted(sides)
+    }
+
+    fn map_values<F: Fn(i64) -> i64>(&self, f: F) -> Die {
+        let sides = 
+            self.sides.iter()
+            .map(|side| DieSide {
+                symbols: side.symbols.clone(),
+                value: Some(f(side.value.unwrap_or_else(|| side.total_value())))
+            })
+            .collect();
+        Die { sides, weights: self.weights.clone() }
+    }
+
+    /// Creates a new [`Die`](crate::dice::Die) with every side's value multiplied by `factor`. A side without a value uses its 
+    /// [`total_value`](crate::dice::DieSide::total_value) as its value, as in [`expected_value`](crate::dice::Die::expected_value). Symbols are left unchanged.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let d6 = standard::d6();
+    /// 
+    /// let doubled = d6.scale_values(2);
+    /// 
+    /// assert_eq!(doubled.sides()[5].value(), Some(12));
+    /// assert_eq!(doubled.expected_value(), 7.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scale_values(&self, factor: i64) -> Die {
+        self.map_values(|v| v * factor)
+    }
+
+    /// Creates a new [`Die`](crate::dice::Die) with `offset` added to every side's value. A side without a value uses its 
+    /// [`total_value`](crate::dice::DieSide::total_value) as its value, as in [`expected_value`](crate::dice::Die::expected_value). Symbols are left unchanged.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let d6 = standard::d6();
+    /// 
+    /// let shifted = d6.shift_values(-1);
+    /// 
+    /// assert_eq!(shifted.sides()[0].value(), Some(0));
+    /// assert_eq!(shifted.expected_value(), 2.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shift_values(&self, offset: i64) -> Die {
+        self.map_values(|v| v + offset)
+    }
+
+    fn keep_one_of_two(&self, symbols: &[DieSymbol], keep: Ordering) -> Die {
+        let count_of = |side: &DieSide| symbols.iter().map(|s| side.count_of(s)).sum::<usize>();
+        let mut weights = vec![0; self.sides.len()];
+        for (first, first_side) in self.sides.iter().enumerate() {
+            for (second, second_side) in self.sides.iter().enumerate() {
+                let kept = 
+                    if count_of(second_side).cmp(&count_of(first_side)) == keep { second } 
+                    else { first };
+                weights[kept] += self.weights[first] * self.weights[second];
+            }
+        }
+        Die { sides: self.sides.clone(), weights }
+    }
+
+    /// Creates a new weighted [`Die`](crate::dice::Die) representing the die being rolled twice, keeping the side with the most of the 
+    /// provided [`DieSymbols`](crate::dice::DieSymbol). When both sides show as many, the first roll is kept
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let d20 = standard::d20();
+    /// 
+    /// let advantage = d20.with_advantage(&[ standard::pip() ]);
+    /// 
+    /// assert_eq!(advantage.total_weight(), 400);
+    /// assert_eq!(advantage.weights()[19], 39);
+    /// assert_eq!(advantage.average_of(&standard::pip()), 13.825);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_advantage(&self, symbols: &[DieSymbol]) -> Die {
+        self.keep_one_of_two(symbols, Ordering::Greater)
+    }
+
+    /// Creates a new weighted [`Die`](crate::dice::Die) representing the die being rolled twice, keeping the side with the fewest of the 
+    /// provided [`DieSymbols`](crate::dice::DieSymbol). When both sides show as many, the first roll is kept
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let d20 = standard::d20();
+    /// 
+    /// let disadvantage = d20.with_disadvantage(&[ standard::pip() ]);
+    /// 
+    /// assert_eq!(disadvantage.weights()[0], 39);
+    /// assert_eq!(disadvantage.average_of(&standard::pip()), 7.175);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_disadvantage(&self, symbols: &[DieSymbol]) -> Die {
+        self.keep_one_of_two(symbols, Ordering::Less)
+    }
+
+    /// Creates a new [`Die`](crate::dice::Die) with every [`DieSymbol`](crate::dice::DieSymbol) on every side replaced by the result of `f`. 
+    /// The sides keep their order, weights and values.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # fn main() -> Result<(), String> {
+    /// let damage = DieSymbol::new("Damage")?;
+    /// 
+    /// let damage_d6 = standard::d6().map_symbols(|_| damage.clone());
+    /// 
+    /// assert_eq!(damage_d6.unique_symbols(), vec![ damage.clone() ]);
+    /// assert_eq!(damage_d6.average_of(&damage), 3.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_symbols(&self, f: impl Fn(&DieSymbol) -> DieSymbol) -> Die {
+        let sides = 
+            self.sides.iter()
+            .map(|side| DieSide {
+                symbols: side.symbols.iter().map(&f).collect(),
+                value: side.value
+            })
+            .collect();
+        Die { sides, weights: self.weights.clone() }
+    }
+}
+
+/// Rolls each [`Die`](crate::dice::Die) in the pool once, returning the chosen [`DieSides`](crate::dice::DieSide) in the same order as the dice
+/// 
+/// This is the raw physical roll; no [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) is applied to the result
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::{roll_pool, standard};
+/// # fn main() -> Result<(), String> {
+/// let pool = vec![ standard::d6(), standard::d6(), standard::d4() ];
+/// 
+/// let sides = roll_pool(&pool, &mut rand::thread_rng());
+/// 
+/// assert_eq!(sides.len(), 3);
+/// assert!((1..=4).contains(&sides[2].symbols().len()));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn roll_pool<'a>(dice: &'a [Die], rng: &mut impl Rng) -> Vec<&'a DieSide> {
+    dice.iter()
+        .map(|d| d.roll(rng))
+        .collect()
+}
+
+#[derive(Clone, Debug, Default)]
+/// Builds a [`Die`](crate::dice::Die) one [`DieSide`](crate::dice::DieSide) at a time
+pub struct DieBuilder {
+    sides: Vec<DieSide>
+}
+impl DieBuilder {
+    /// Creates a new [`DieBuilder`](crate::dice::DieBuilder) without any sides
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::dice::DieBuilder;
+    /// let builder = DieBuilder::new();
+    /// 
+    /// assert!(builder.build().is_err());
+    /// ```
+    pub fn new() -> DieBuilder {
+        DieBuilder { sides: Vec::new() }
+    }
+
+    /// Adds a side showing the provided [`DieSymbols`](crate::dice::DieSymbol)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieBuilder};
+    /// # fn main() -> Result<(), String> {
+    /// let heads = DieSymbol::new("Heads")?;
+    /// let tails = DieSymbol::new("Tails")?;
+    /// 
+    /// let coin = DieBuilder::new()
+    ///     .add_side(vec![ heads ])
+    ///     .add_side(vec![ tails ])
+    ///     .build()?;
+    /// 
+    /// assert_eq!(coin.sides().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_side(mut self, symbols: Vec<DieSymbol>) -> DieBuilder {
+        self.sides.push(DieSide::new(symbols));
+        self
+    }
+
+    /// Adds a blank side without any [`DieSymbols`](crate::dice::DieSymbol)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieBuilder};
+    /// # fn main() -> Result<(), String> {
+    /// let hit = DieSymbol::new("Hit")?;
+    /// 
+    /// let die = DieBuilder::new()
+    ///     .add_side(vec![ hit ])
+    ///     .add_blank_side()
+    ///     .build()?;
+    /// 
+    /// assert!(die.sides()[1].symbols().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_blank_side(self) -> DieBuilder {
+        self.add_side(vec![])
+    }
+
+    /// Adds `times` identical sides showing the provided [`DieSymbols`](crate::dice::DieSymbol)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieBuilder};
+    /// # fn main() -> Result<(), String> {
+    /// let hit = DieSymbol::new("Hit")?;
+    /// 
+    /// let die = DieBuilder::new()
+    ///     .add_repeated_side(vec![ hit.clone() ], 3)
+    ///     .add_blank_side()
+    ///     .build()?;
+    /// 
+    /// assert_eq!(die.sides().len(), 4);
+    /// assert_eq!(die.average_of(&hit), 0.75);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_repeated_side(mut self, symbols: Vec<DieSymbol>, times: usize) -> DieBuilder {
+        self.sides.extend((0..times).map(|_| DieSide::new(symbols.clone())));
+        self
+    }
+
+    /// Creates the [`Die`](crate::dice::Die) from the added sides, in the order they were added. 
+    /// Returns `Err` if fewer than 2 sides were added, as in [`Die::new`](crate::dice::Die::new), else returns `Ok`
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieBuilder};
+    /// # fn main() -> Result<(), String> {
+    /// let hit = DieSymbol::new("Hit")?;
+    /// let base = DieBuilder::new().add_side(vec![ hit.clone() ]);
+    /// 
+    /// let die = base.clone().add_blank_side().build()?;
+    /// 
+    /// assert_eq!(die.sides().len(), 2);
+    /// assert!(base.build().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(self) -> Result<Die, String> {
+        Die::new(self.sides)
+    }
+}
+
+impl PartialEq for DieSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for DieSymbol {}
+
+impl Hash for DieSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl PartialOrd for DieSymbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DieSymbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl PartialEq for DieSide {
+    fn eq(&self, other: &Self) -> bool {
+        let mut symbols = self.symbols.clone();
+        let mut other_symbols = other.symbols.clone();
+        symbols.sort();
+        other_symbols.sort();
+        self.value == other.value && symbols == other_symbols
+    }
+}
+
+impl Eq for DieSide {}
+
+impl PartialEq for Die {
+    fn eq(&self, other: &Self) -> bool {
+        let count_in = |die: &Die, side: &DieSide, weight: usize| 
+            die.weighted_sides().filter(|(s, w)| *s == side && *w == weight).count();
+        self.sides.len() == other.sides.len() &&
+            self.weighted_sides().all(|(side, weight)| count_in(self, side, weight) == count_in(other, side, weight))
+    }
+}
+
+impl Eq for Die {}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SymbolRepr {
+    Name(String),
+    Valued { name: String, value: i64 }
+}
+
+#[cfg(feature = "serde")]
+impl From<DieSymbol> for SymbolRepr {
+    fn from(symbol: DieSymbol) -> Self {
+        match symbol.value {
+            1 => SymbolRepr::Name(symbol.name),
+            value => SymbolRepr::Valued { name: symbol.name, value }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SymbolRepr> for DieSymbol {
+    type Error = String;
+
+    fn try_from(repr: SymbolRepr) -> Result<Self, Self::Error> {
+        match repr {
+            SymbolRepr::Name(name) => DieSymbol::new(name),
+            SymbolRepr::Valued { name, value } => DieSymbol::new_valued(name, value)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct DieRepr {
+    sides: Vec<DieSide>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weights: Option<Vec<usize>>
+}
+
+#[cfg(feature = "serde")]
+impl From<Die> for DieRepr {
+    fn from(die: Die) -> Self {
+        let weights = 
+            if die.weights.iter().all(|&w| w == 1) { None } 
+            else { Some(die.weights) };
+        DieRepr { sides: die.sides, weights }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DieRepr> for Die {
+    type Error = String;
+
+    fn try_from(repr: DieRepr) -> Result<Self, Self::Error> {
+        match repr.weights {
+            None => Die::new(repr.sides),
+            Some(weights) if weights.len() == repr.sides.len() => 
+                Die::new_weighted(repr.sides.into_iter().zip(weights).collect()),
+            Some(_) => Err("Die must have one weight per side".to_string())
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,234 @@
+use crate::dice::*;
+use crate::dice::standard::{n_sided_die, pip};
+use crate::rolls::RollCollectionPolicy;
+
+/// Represents a roll written in standard dice notation, such as `4d6k3+2`, split into its dice, flat modifier and keep count
+pub struct ParsedRoll {
+    dice: Vec<Die>,
+    modifier: i64,
+    keep_highest: Option<usize>
+}
+
+impl ParsedRoll {
+    /// Returns a slice of the standard [`Dice`](crate::dice::Die) rolled, one per die in the notation
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::notation;
+    /// # fn main() -> Result<(), String> {
+    /// let roll = notation::parse_roll("2d6+1d4")?;
+    /// 
+    /// assert_eq!(roll.dice().len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dice(&self) -> &[Die] {
+        self.dice.as_slice()
+    }
+
+    /// Returns the sum of the flat modifiers in the notation, or `0` if there are none
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::notation;
+    /// # fn main() -> Result<(), String> {
+    /// let roll = notation::parse_roll("1d20+5-2")?;
+    /// 
+    /// assert_eq!(roll.modifier(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn modifier(&self) -> i64 {
+        self.modifier
+    }
+
+    /// Returns the number of highest dice kept with a `k` suffix, or `None` if every die is kept
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::notation;
+    /// # fn main() -> Result<(), String> {
+    /// let roll = notation::parse_roll("4d6k3")?;
+    /// 
+    /// assert_eq!(roll.keep_highest(), Some(3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keep_highest(&self) -> Option<usize> {
+        self.keep_highest
+    }
+
+    /// Returns the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) matching the notation: taking the highest N dice for a `k` suffix,
+    /// otherwise collecting all dice
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{notation, standard};
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let roll = notation::parse_roll("2d6k1")?;
+    /// 
+    /// let results = RollProbabilities::new(roll.dice(), &roll.policy(&symbols))?;
+    /// 
+    /// assert_eq!(results.get_odds(&[RollTarget::exactly_n_of(1, &symbols)]), 1.0 / 36.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn policy<'a>(&self, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        match self.keep_highest {
+            Some(n) => RollCollectionPolicy::take_highest_n_of(n, symbols),
+            None => RollCollectionPolicy::collect_all(symbols)
+        }
+    }
+}
+
+const MAX_MODIFIER: i64 = 1_000;
+const MAX_DICE: usize = 100;
+const MAX_SIDES: usize = 1_000;
+
+fn parse_number<T: std::str::FromStr>(text: &str, what: &str, term: &str) -> Result<T, String> {
+    if text.is_empty() {
+        return Err(format!("missing {} in '{}'", what, term));
+    }
+    text.parse().map_err(|_| format!("'{}' is not a valid {} in '{}'", text, what, term))
+}
+
+/// Parses standard dice notation into a [`ParsedRoll`](crate::dice::notation::ParsedRoll). The notation is a sum of terms, where `NdM` is
+/// N standard M-sided dice (N defaults to `1`), `NdMkX` also keeps only the highest X of those dice, and a plain number is a flat modifier
+/// that may be added or subtracted. Whitespace is ignored and `d` and `k` may be upper case. A `k` suffix is only allowed when the notation
+/// has a single dice term, and must keep between 1 and N dice. The notation may roll at most 100 dice of at most 1000 sides each, 
+/// and the summed modifier must be within ±1000.
+/// Returns `Err` describing the problem if the notation is malformed, else returns `Ok`
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::notation;
+/// # fn main() -> Result<(), String> {
+/// let roll = notation::parse_roll("4d6k3 + 2")?;
+/// 
+/// assert_eq!(roll.dice().len(), 4);
+/// assert_eq!(roll.keep_highest(), Some(3));
+/// assert_eq!(roll.modifier(), 2);
+/// assert!(notation::parse_roll("3d").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_roll(input: &str) -> Result<ParsedRoll, String> {
+    let text: String =
+        input.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if text.is_empty() {
+        return Err("notation cannot be empty".to_string());
+    }
+    let mut terms: Vec<(bool, String)> = Vec::new();
+    let mut negative = false;
+    let mut current = String::new();
+    for c in text.chars() {
+        if c == '+' || c == '-' {
+            if !current.is_empty() || !terms.is_empty() {
+                terms.push((negative, current));
+            }
+            negative = c == '-';
+            current = String::new();
+        } else {
+            current.push(c);
+        }
+    }
+    terms.push((negative, current));
+
+    let mut roll = ParsedRoll { dice: Vec::new(), modifier: 0, keep_highest: None };
+    let mut dice_terms = 0;
+    for (negative, term) in terms {
+        if term.is_empty() {
+            return Err(format!("missing term in '{}'", input));
+        }
+        match term.split_once('d') {
+            Some((count, rest)) => {
+                if negative {
+                    return Err(format!("dice cannot be subtracted in '{}'", input));
+                }
+                let count: usize = if count.is_empty() { 1 } else { parse_number(count, "number of dice", &term)? };
+                if count == 0 {
+                    return Err(format!("must roll at least one die in '{}'", term));
+                }
+                if count > MAX_DICE - roll.dice.len() {
+                    return Err(format!("cannot roll more than {} dice in '{}'", MAX_DICE, input));
+                }
+                let (sides, keep) = match rest.split_once('k') {
+                    Some((sides, keep)) => (sides, Some(parse_number::<usize>(keep, "number of dice to keep", &term)?)),
+                    None => (rest, None)
+                };
+                let sides: usize = parse_number(sides, "number of sides", &term)?;
+                if sides < 2 {
+                    return Err(format!("dice must have at least 2 sides in '{}'", term));
+                }
+                if sides > MAX_SIDES {
+                    return Err(format!("dice cannot have more than {} sides in '{}'", MAX_SIDES, term));
+                }
+                if let Some(keep) = keep {
+                    if keep == 0 || keep > count {
+                        return Err(format!("must keep between 1 and {} dice in '{}'", count, term));
+                    }
+                }
+                dice_terms += 1;
+                if keep.is_some() {
+                    roll.keep_highest = keep;
+                }
+                roll.dice.extend((0..count).map(|_| n_sided_die(sides)));
+            },
+            None => {
+                let amount: i64 = parse_number(&term, "number", &term)?;
+                roll.modifier += if negative { -amount } else { amount };
+                if roll.modifier.abs() > MAX_MODIFIER {
+                    return Err(format!("modifier must be between -{0} and {0} in '{1}'", MAX_MODIFIER, input));
+                }
+            }
+        }
+    }
+    if dice_terms == 0 {
+        return Err(format!("must include at least one dice term in '{}'", input));
+    }
+    if roll.keep_highest.is_some() && dice_terms > 1 {
+        return Err(format!("keeping dice is only supported with a single dice term in '{}'", input));
+    }
+    Ok(roll)
+}
+
+/// Parses standard dice notation, as described in [`parse_roll`](crate::dice::notation::parse_roll), into a collection of [`Dice`](crate::dice::Die).
+/// A positive flat modifier is added as a constant die showing that many pips, so it counts towards pip totals like the dice do. 
+/// A die can't show fewer than zero pips, so a modifier that sums to less than zero is rejected; use [`parse_roll`](crate::dice::notation::parse_roll) 
+/// to keep it as a signed offset. A `k` suffix is checked but dropped; use [`parse_roll`](crate::dice::notation::parse_roll) to get its 
+/// [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy). Returns `Err` describing the problem if the notation is malformed, else returns `Ok`
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::notation;
+/// # fn main() -> Result<(), String> {
+/// let dice = notation::parse("3d6+2")?;
+/// 
+/// assert_eq!(dice.len(), 4);
+/// assert_eq!(dice.iter().map(|d| d.expected_value()).sum::<f64>(), 12.5);
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse(input: &str) -> Result<Vec<Die>, String> {
+    let roll = parse_roll(input)?;
+    if roll.modifier < 0 {
+        return Err(format!("a negative modifier can't be shown as pips in '{}', use parse_roll to keep it as an offset", input));
+    }
+    let mut dice = roll.dice;
+    if roll.modifier > 0 {
+        let side = DieSide::with_value(vec![ pip(); roll.modifier as usize ], roll.modifier);
+        dice.push(Die::new(vec![ side.clone(), side ])?);
+    }
+    Ok(dice)
+}
\ No newline at end of file
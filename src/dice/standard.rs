@@ -5,16 +5,16 @@ fn side_of_n_symbols(n: usize, symbol: &DieSymbol) -> DieSide {
         (0..n)
         .map(|_| symbol.clone())
         .collect::<Vec<_>>();
-    DieSide { symbols: vec }
+    DieSide { symbols: vec, value: None }
 }
 
-fn n_sided_die(n: usize) -> Die {
+pub(crate) fn n_sided_die(n: usize) -> Die {
     let pip = pip();
-    let sides = 
+    let sides: Vec<DieSide> = 
         (1..(n+1))
         .map(|i| side_of_n_symbols(i, &pip))
         .collect();
-    Die { sides }
+    Die { weights: vec![1; sides.len()], sides }
 }
 
 /// Creates an instance of the symbol used by the standard dice
@@ -22,6 +22,16 @@ pub fn pip() -> DieSymbol {
     DieSymbol::new("Pip").unwrap()
 }
 
+/// Creates an instance of the symbol shown on the plus sides of a Fate die, with a value of `1`
+pub fn plus() -> DieSymbol {
+    DieSymbol::new_valued("Plus", 1).unwrap()
+}
+
+/// Creates an instance of the symbol shown on the minus sides of a Fate die, with a value of `-1`
+pub fn minus() -> DieSymbol {
+    DieSymbol::new_valued("Minus", -1).unwrap()
+}
+
 /// Creates a standard 4 sided die
 pub fn d4() -> Die {
     n_sided_die(4)
@@ -51,3 +61,57 @@ pub fn d12() -> Die {
 pub fn d20() -> Die {
     n_sided_die(20)
 }
+
+/// Creates a standard 100 sided die
+pub fn d100() -> Die {
+    n_sided_die(100)
+}
+
+/// Creates a percentile pair of a tens die and a ones die, in that order, shown with [valued symbols](crate::dice::DieSymbol::new_valued). 
+/// The tens die shows `00` to `90` and the ones die `0` to `9`, each valued as shown, so the summed value runs evenly from 0 to 99 
+/// where `00` and `0` together are read as 100, as done by [`percentile_total`](crate::dice::standard::percentile_total)
+pub fn percentile_pair() -> Vec<Die> {
+    let valued_die = |symbols: Vec<DieSymbol>| {
+        let sides: Vec<DieSide> = symbols.iter().map(|s| side_of_n_symbols(1, s)).collect();
+        Die { weights: vec![1; sides.len()], sides }
+    };
+    let tens = 
+        (0..10)
+        .map(|i| DieSymbol::new_valued(format!("{:02}", i * 10), i * 10).unwrap())
+        .collect();
+    let ones = 
+        (0..10)
+        .map(|i| DieSymbol::new_valued(i.to_string(), i).unwrap())
+        .collect();
+    vec![ valued_die(tens), valued_die(ones) ]
+}
+
+/// Reads the result of a [`percentile_pair`](crate::dice::standard::percentile_pair) roll from 1 to 100 given its summed value, reading `00` and `0` as 100
+pub fn percentile_total(summed_value: i64) -> i64 {
+    if summed_value == 0 { 100 } else { summed_value }
+}
+
+/// Creates a Fate (dF) die with two plus sides, two minus sides and two blank sides
+pub fn df() -> Die {
+    let plus = plus();
+    let minus = minus();
+    let sides: Vec<DieSide> = vec![
+        side_of_n_symbols(1, &plus),
+        side_of_n_symbols(1, &plus),
+        side_of_n_symbols(1, &minus),
+        side_of_n_symbols(1, &minus),
+        side_of_n_symbols(0, &plus),
+        side_of_n_symbols(0, &plus)
+    ];
+    Die { weights: vec![1; sides.len()], sides }
+}
+
+/// Creates a die with one side per provided value, where each side carries only its value and no symbols. 
+/// Returns `Err` if fewer than 2 values are provided
+pub fn value_die(values: &[i64]) -> Result<Die, String> {
+    let sides = 
+        values.iter()
+        .map(|&v| DieSide::with_value(vec![], v))
+        .collect();
+    Die::new(sides)
+}
\ No newline at end of file
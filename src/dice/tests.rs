@@ -2,8 +2,8 @@ use crate::dice::*;
 use crate::dice::standard::*;
 
 fn assert_dice_sides(sides: &[DieSide]) {
-    for i in 0..(sides.len()) {
-        assert_eq!(sides[i].symbols().len(), i+1);
+    for (i, side) in sides.iter().enumerate() {
+        assert_eq!(side.symbols().len(), i+1);
     }
 }
 
@@ -41,4 +41,557 @@ fn ten_sided_die_average() {
     let symbol = die.unique_symbols().first().unwrap().clone();
     let average = die.average_of(&symbol);
     assert_eq!(average, 5.5);
+}
+
+#[test]
+fn valued_die_expected_value() {
+    let coin = DieSymbol::new("Coin").unwrap();
+    let sides = vec![
+        DieSide::with_value(vec![ coin.clone() ], -2),
+        DieSide::with_value(vec![ coin.clone(), coin.clone() ], 0),
+        DieSide::with_value(vec![], 5),
+        DieSide::with_value(vec![], 1)
+    ];
+    let die = Die::new(sides).unwrap();
+    assert_eq!(die.expected_value(), 1.0);
+    assert_eq!(die.average_of(&coin), 0.75);
+}
+
+#[test]
+fn unvalued_die_expected_value() {
+    let die = d6();
+    assert_eq!(die.expected_value(), 3.5);
+    let pip = pip();
+    let mixed = Die::new(vec![
+        DieSide::new(vec![ pip.clone(), pip.clone() ]),
+        DieSide::with_value(vec![ pip ], 6)
+    ]).unwrap();
+    assert_eq!(mixed.expected_value(), 4.0);
+}
+
+#[test]
+fn custom_die_self_tie_probability() {
+    let hit = DieSymbol::new("Hit").unwrap();
+    let miss = DieSymbol::new("Miss").unwrap();
+    let sides = vec![
+        DieSide::new(vec![ hit.clone() ]),
+        DieSide::new(vec![ hit.clone() ]),
+        DieSide::new(vec![ hit.clone(), hit.clone() ]),
+        DieSide::new(vec![ miss.clone() ])
+    ];
+    let die = Die::new(sides).unwrap();
+    assert_eq!(die.self_tie_probability(&hit), 0.25 + 0.0625 + 0.0625);
+    assert_eq!(die.self_tie_probability(&miss), 0.0625 + 0.5625);
+    assert!((d20().self_tie_probability(&pip()) - 0.05).abs() < 1e-12);
+}
+
+#[test]
+fn custom_die_product() {
+    let heads = DieSymbol::new("Heads").unwrap();
+    let tails = DieSymbol::new("Tails").unwrap();
+    let coin = Die::new(vec![
+        DieSide::new(vec![ heads.clone() ]),
+        DieSide::new(vec![ tails.clone() ])
+    ]).unwrap();
+    let compound = coin.product(&d4()).unwrap();
+
+    assert_eq!(compound.sides().len(), 8);
+    assert_eq!(compound.unique_symbols().len(), 3);
+    assert_eq!(compound.average_of(&heads), 0.5);
+    assert_eq!(compound.average_of(&pip()), 2.5);
+    assert_eq!(compound.sides()[5].symbols().len(), 3);
+    assert_eq!(compound.sides()[5].value(), None);
+}
+
+#[test]
+fn valued_die_product() {
+    let plain = Die::new(vec![ DieSide::new(vec![ pip() ]), DieSide::new(vec![ ]) ]).unwrap();
+    let valued = Die::new(vec![ DieSide::with_value(vec![], 10), DieSide::with_value(vec![], -1) ]).unwrap();
+    let compound = plain.product(&valued).unwrap();
+
+    let values: Vec<Option<i64>> = compound.sides().iter().map(|s| s.value()).collect();
+    assert_eq!(values, vec![ Some(11), Some(0), Some(10), Some(-1) ]);
+    assert_eq!(compound.expected_value(), plain.expected_value() + valued.expected_value());
+}
+
+#[test]
+fn coin_value_die() {
+    let die = value_die(&[-1, 0, 0, 1, 2, 4]).unwrap();
+    assert_eq!(die.sides().len(), 6);
+    assert!(die.unique_symbols().is_empty());
+    assert_eq!(die.sides()[0].value(), Some(-1));
+    assert_eq!(die.expected_value(), 1.0);
+    assert!(value_die(&[3]).is_err());
+    assert!(value_die(&[]).is_err());
+}
+
+#[test]
+fn scale_and_shift_values() {
+    let die = value_die(&[-2, 0, 5]).unwrap();
+    let scaled = die.scale_values(3);
+    let shifted = die.shift_values(2);
+    let scaled_values: Vec<Option<i64>> = scaled.sides().iter().map(|s| s.value()).collect();
+    let shifted_values: Vec<Option<i64>> = shifted.sides().iter().map(|s| s.value()).collect();
+
+    assert_eq!(scaled_values, vec![ Some(-6), Some(0), Some(15) ]);
+    assert_eq!(shifted_values, vec![ Some(0), Some(2), Some(7) ]);
+    assert_eq!(scaled.expected_value(), die.expected_value() * 3.0);
+    assert_eq!(shifted.expected_value(), die.expected_value() + 2.0);
+
+    let scaled_d4 = d4().scale_values(-1);
+    assert_eq!(scaled_d4.expected_value(), -2.5);
+    assert_eq!(scaled_d4.average_of(&pip()), 2.5);
+}
+
+#[test]
+fn hundred_sided_die() {
+    let die = d100();
+    assert_eq!(die.sides().len(), 100);
+    assert_dice_sides(die.sides());
+    assert_eq!(die.expected_value(), 50.5);
+}
+
+#[test]
+fn percentile_pair_dice() {
+    let pair = percentile_pair();
+    assert_eq!(pair.len(), 2);
+    let names = |die: &Die| die.sides().iter().map(|s| s.symbols()[0].name().to_string()).collect::<Vec<_>>();
+    assert_eq!(names(&pair[0]), vec![ "00", "10", "20", "30", "40", "50", "60", "70", "80", "90" ]);
+    assert_eq!(names(&pair[1]), vec![ "0", "1", "2", "3", "4", "5", "6", "7", "8", "9" ]);
+    assert_eq!(pair[0].expected_value(), 45.0);
+    assert_eq!(pair[1].expected_value(), 4.5);
+}
+
+#[test]
+fn percentile_pair_face_totals() {
+    let pair = percentile_pair();
+    let total = |tens: usize, ones: usize| percentile_total(pair[0].sides()[tens].total_value() + pair[1].sides()[ones].total_value());
+    assert_eq!(total(0, 0), 100);
+    assert_eq!(total(0, 1), 1);
+    assert_eq!(total(1, 0), 10);
+    assert_eq!(total(1, 5), 15);
+    assert_eq!(total(9, 0), 90);
+    assert_eq!(total(9, 9), 99);
+}
+
+#[test]
+fn fate_die() {
+    let die = df();
+    let sides = die.sides();
+    let count_of = |symbol: &DieSymbol| sides.iter().filter(|s| s.symbols() == [ symbol.clone() ]).count();
+    assert_eq!(sides.len(), 6);
+    assert_eq!(count_of(&plus()), 2);
+    assert_eq!(count_of(&minus()), 2);
+    assert_eq!(sides.iter().filter(|s| s.symbols().is_empty()).count(), 2);
+    assert_eq!(die.unique_symbols().len(), 2);
+    assert_eq!(die.expected_value(), 0.0);
+}
+
+#[test]
+fn max_and_min_of_symbol() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let die = Die::new(vec![
+        DieSide::new(vec![ sword.clone(), sword.clone() ]),
+        DieSide::new(vec![ sword.clone(), shield.clone() ]),
+        DieSide::new(vec![ sword.clone() ])
+    ]).unwrap();
+    assert_eq!(die.max_of(&sword), 2);
+    assert_eq!(die.min_of(&sword), 1);
+    assert_eq!(die.max_of(&shield), 1);
+    assert_eq!(die.min_of(&shield), 0);
+    assert_eq!(d20().max_of(&pip()), 20);
+    assert_eq!(df().min_of(&plus()), 0);
+}
+
+#[test]
+fn reordered_sides_and_dice_are_equal() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let sword_shield = DieSide::new(vec![ sword.clone(), shield.clone() ]);
+    let shield_sword = DieSide::new(vec![ shield.clone(), sword.clone() ]);
+    assert_eq!(sword_shield, shield_sword);
+    assert_ne!(sword_shield, DieSide::new(vec![ sword.clone(), sword.clone() ]));
+    assert_ne!(DieSide::new(vec![ sword.clone() ]), DieSide::with_value(vec![ sword.clone() ], 3));
+
+    let die = Die::new(vec![ sword_shield, DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![]) ]).unwrap();
+    let reordered = Die::new(vec![ DieSide::new(vec![]), shield_sword, DieSide::new(vec![ sword.clone() ]) ]).unwrap();
+    assert_eq!(die, reordered);
+    assert_eq!(d6(), d6());
+    assert_ne!(d6(), d8());
+    assert_ne!(
+        Die::new(vec![ DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![ shield.clone() ]) ]).unwrap(),
+        Die::new(vec![ DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![ shield.clone() ]), DieSide::new(vec![ shield.clone() ]) ]).unwrap());
+    assert_ne!(
+        Die::new_weighted(vec![ (DieSide::new(vec![ sword.clone() ]), 2), (DieSide::new(vec![ shield.clone() ]), 1) ]).unwrap(),
+        Die::new_weighted(vec![ (DieSide::new(vec![ sword ]), 1), (DieSide::new(vec![ shield ]), 2) ]).unwrap());
+}
+
+#[test]
+fn builder_variants_from_template() {
+    let success = DieSymbol::new("Success").unwrap();
+    let advantage = DieSymbol::new("Advantage").unwrap();
+    let template = DieBuilder::new()
+        .add_blank_side()
+        .add_repeated_side(vec![ success.clone() ], 2)
+        .add_side(vec![ success.clone(), advantage.clone() ]);
+
+    let basic = template.clone().build().unwrap();
+    let improved = template.add_repeated_side(vec![ advantage.clone(), advantage.clone() ], 2).build().unwrap();
+
+    assert_eq!(basic.sides().len(), 4);
+    assert_eq!(basic.average_of(&success), 0.75);
+    assert_eq!(improved.sides().len(), 6);
+    assert_eq!(improved.average_of(&advantage), 5.0 / 6.0);
+    assert_eq!(basic, Die::new(vec![
+        DieSide::new(vec![ advantage, success.clone() ]),
+        DieSide::new(vec![ success.clone() ]),
+        DieSide::new(vec![]),
+        DieSide::new(vec![ success ])
+    ]).unwrap());
+}
+
+#[test]
+fn side_symbol_counts() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let side = DieSide::new(vec![ sword.clone(), shield.clone(), sword.clone() ]);
+    let blank = DieSide::new(vec![]);
+    assert_eq!(side.count_of(&sword), 2);
+    assert_eq!(side.count_of(&shield), 1);
+    assert_eq!(side.count_of(&pip()), 0);
+    assert!(side.contains(&shield));
+    assert!(!side.contains(&pip()));
+    assert_eq!(blank.count_of(&sword), 0);
+    assert!(!blank.contains(&sword));
+}
+
+#[test]
+fn relabel_d6_symbols() {
+    let hit = DieSymbol::new("Hit").unwrap();
+    let die = d6().map_symbols(|s| if *s == pip() { hit.clone() } else { s.clone() });
+    assert_eq!(die.unique_symbols(), vec![ hit.clone() ]);
+    assert_eq!(die.sides().len(), 6);
+    assert_eq!(die.max_of(&hit), 6);
+    assert_eq!(die.average_of(&pip()), 0.0);
+
+    let loaded = Die::new_weighted(vec![
+        (DieSide::with_value(vec![ pip() ], 4), 3),
+        (DieSide::new(vec![ pip(), hit.clone() ]), 1)
+    ]).unwrap();
+    let relabeled = loaded.map_symbols(|s| if *s == pip() { hit.clone() } else { s.clone() });
+    assert_eq!(relabeled.weights(), loaded.weights());
+    assert_eq!(relabeled.sides()[0].value(), Some(4));
+    assert_eq!(relabeled.sides()[1].count_of(&hit), 2);
+}
+
+#[test]
+fn symbols_created_together() {
+    let symbols = DieSymbol::new_many(&[ " Sword", "Shield ", "Blank" ]).unwrap();
+
+    assert_eq!(symbols, vec![ DieSymbol::new("Sword").unwrap(), DieSymbol::new("Shield").unwrap(), DieSymbol::new("Blank").unwrap() ]);
+    assert!(DieSymbol::new_many(&[]).unwrap().is_empty());
+    let err = DieSymbol::new_many(&[ "Sword", "", " \t" ]).unwrap_err();
+    assert!(err.contains("index 1"));
+}
+
+#[test]
+fn die_from_faces() {
+    let zero_based = Die::from_faces(&[ 0, 1, 2, 3, 4, 5 ]).unwrap();
+    let zero = DieSymbol::new_valued("0", 0).unwrap();
+
+    assert_eq!(zero_based.sides().len(), 6);
+    assert_eq!(zero_based.unique_symbols().len(), 6);
+    assert_eq!(zero_based.expected_value(), 2.5);
+    assert_eq!(zero_based.average_of(&zero), 1.0 / 6.0);
+    assert_eq!(zero_based.sides()[5].symbols(), &[ DieSymbol::new_valued("5", 5).unwrap() ]);
+
+    let lumpy = Die::from_faces(&[ 1, 2, 2, -3 ]).unwrap();
+    assert_eq!(lumpy.unique_symbols().len(), 3);
+    assert_eq!(lumpy.sides()[1], lumpy.sides()[2]);
+    assert_eq!(lumpy.expected_value(), 0.5);
+    assert!(Die::from_faces(&[ 4 ]).is_err());
+    assert!(Die::from_faces(&[]).is_err());
+}
+
+#[test]
+fn die_variance_and_std_dev() {
+    let blank = DieSymbol::new("Blank").unwrap();
+    let loaded = Die::new_weighted(vec![ (DieSide::new(vec![ pip() ]), 3), (DieSide::new(vec![]), 1) ]).unwrap();
+
+    assert!((d6().variance_of(&pip()) - 35.0 / 12.0).abs() < 1e-12);
+    assert!((d6().std_dev_of(&pip()) - (35.0_f64 / 12.0).sqrt()).abs() < 1e-12);
+    assert_eq!(d4().variance_of(&pip()), 1.25);
+    assert_eq!(loaded.variance_of(&pip()), 0.1875);
+    assert_eq!(d6().variance_of(&blank), 0.0);
+    assert_eq!(d6().std_dev_of(&blank), 0.0);
+}
+
+#[test]
+fn side_symbol_counts_as_item_counter() {
+    let star = DieSymbol::new("Star").unwrap();
+    let side = DieSide::new(vec![ pip(), star.clone(), pip(), pip() ]);
+    let reordered = DieSide::new(vec![ star.clone(), pip(), pip(), pip() ]);
+    let counts = side.symbol_counts();
+
+    assert_eq!(counts.get_count(&pip()), 3);
+    assert_eq!(counts.get_count(&star), 1);
+    assert_eq!(counts.total_count(), 4);
+    assert_eq!(counts, reordered.symbol_counts());
+    assert_eq!(side.symbols().len(), 4);
+    assert_eq!(DieSide::new(vec![]).symbol_counts().distinct_count(), 0);
+}
+
+#[test]
+fn symbol_identity_ignores_value() {
+    use std::collections::HashSet;
+    let plain = DieSymbol::new("Five").unwrap();
+    let valued = DieSymbol::new_valued("Five", 5).unwrap();
+    assert_eq!(plain, valued);
+    assert_eq!(plain.cmp(&valued), std::cmp::Ordering::Equal);
+    assert_eq!([ plain, valued ].iter().collect::<HashSet<_>>().len(), 1);
+    assert_ne!(DieSymbol::new_valued("Four", 5).unwrap(), DieSymbol::new_valued("Five", 5).unwrap());
+}
+
+#[test]
+fn symbol_rejects_whitespace_only() {
+    assert!(DieSymbol::new("").is_err());
+    assert!(DieSymbol::new("   ").is_err());
+    assert!(DieSymbol::new("\t\n").is_err());
+    assert!(DieSymbol::new_normalized(" \t \n ").is_err());
+}
+
+#[test]
+fn symbol_keeps_internal_whitespace() {
+    let symbol = DieSymbol::new("  Dark Elf\n").unwrap();
+    assert_eq!(symbol.name(), "Dark Elf");
+    let spaced = DieSymbol::new("Dark   Elf").unwrap();
+    assert_eq!(spaced.name(), "Dark   Elf");
+    assert_ne!(symbol, spaced);
+}
+
+#[test]
+fn normalized_symbol_collapses_internal_whitespace() {
+    let normalized = DieSymbol::new_normalized("Dark \t  Elf").unwrap();
+    assert_eq!(normalized.name(), "Dark Elf");
+    assert_eq!(normalized, DieSymbol::new("Dark Elf").unwrap());
+}
+
+#[test]
+fn side_access_is_bounds_checked() {
+    let die = d6();
+    assert_eq!(die.side(0).unwrap().symbols().len(), 1);
+    assert_eq!(die.side(5).unwrap().symbols().len(), 6);
+    assert!(die.side(6).is_none());
+}
+
+#[test]
+fn weighted_values_die() {
+    let die = Die::from_weighted_values(&[ (1, 2), (2, 0), (6, 1) ]).unwrap();
+
+    assert_eq!(die.sides().len(), 2);
+    assert_eq!(die.weights(), &[2, 1]);
+    assert_eq!(die.sides().iter().filter(|s| s.value() == Some(2)).count(), 0);
+    assert_eq!(die.expected_value(), 8.0 / 3.0);
+    assert!(Die::from_weighted_values(&[ (1, 5), (2, 0) ]).is_err());
+    assert!(Die::from_weighted_values(&[]).is_err());
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn roll_lands_on_every_side() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let mut rng = StdRng::seed_from_u64(11);
+    let blank_die = Die::new(vec![
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ pip() ]),
+        DieSide::new(vec![ ]) ]).unwrap();
+    let mut blank_counts = [0; 2];
+    let mut pip_counts = [0; 6];
+    for _ in 0..600 {
+        blank_counts[blank_die.roll(&mut rng).symbols().len()] += 1;
+        pip_counts[d6().roll(&mut rng).symbols().len() - 1] += 1;
+    }
+
+    assert!(blank_counts.iter().all(|c| *c > 0));
+    assert!(blank_counts[0] > blank_counts[1]);
+    assert!(pip_counts.iter().all(|c| *c > 0));
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn roll_pool_rolls_each_die_once() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let mut rng = StdRng::seed_from_u64(5);
+    let blank_die = Die::new(vec![ DieSide::new(vec![ ]), DieSide::new(vec![ ]) ]).unwrap();
+    let pool = vec![ d6(), blank_die, d4() ];
+
+    for _ in 0..200 {
+        let sides = roll_pool(&pool, &mut rng);
+        assert_eq!(sides.len(), 3);
+        assert!(pool[0].sides().contains(sides[0]));
+        assert!(sides[1].symbols().is_empty());
+        assert!(pool[2].sides().contains(sides[2]));
+    }
+    assert!(roll_pool(&[], &mut rng).is_empty());
+}
+
+#[test]
+fn weighted_die() {
+    let blank = DieSymbol::new("Blank").unwrap();
+    let loaded = Die::new_weighted(vec![
+        (DieSide::new(vec![ pip() ]), 1),
+        (DieSide::new(vec![ pip(), pip() ]), 1),
+        (DieSide::new(vec![ pip(), pip(), pip() ]), 2) ]).unwrap();
+
+    assert_eq!(loaded.sides().len(), 3);
+    assert_eq!(loaded.weights(), &[1, 1, 2]);
+    assert_eq!(loaded.total_weight(), 4);
+    assert_eq!(loaded.average_of(&pip()), 9.0 / 4.0);
+    assert_eq!(loaded.expected_value(), 9.0 / 4.0);
+    assert_eq!(loaded.average_of(&blank), 0.0);
+    assert!((loaded.self_tie_probability(&pip()) - 6.0 / 16.0).abs() < 1e-12);
+
+    let compound = loaded.product(&d4()).unwrap();
+    assert_eq!(compound.total_weight(), 16);
+    assert_eq!(compound.average_of(&pip()), 9.0 / 4.0 + 2.5);
+    assert_eq!(loaded.scale_values(2).weights(), &[1, 1, 2]);
+}
+
+#[test]
+fn weighted_die_requires_nonzero_weights() {
+    assert!(Die::new_weighted(vec![ (DieSide::new(vec![ pip() ]), 1), (DieSide::new(vec![]), 0) ]).is_err());
+    assert!(Die::new_weighted(vec![ (DieSide::new(vec![ pip() ]), 5) ]).is_err());
+    assert_eq!(d6().weights(), &[1; 6]);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn weighted_roll_follows_weights() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let loaded_coin = Die::new_weighted(vec![ (DieSide::new(vec![ pip() ]), 3), (DieSide::new(vec![]), 1) ]).unwrap();
+
+    let pips: usize = (0..4000).map(|_| loaded_coin.roll(&mut rng).symbols().len()).sum();
+
+    assert!((pips as f64 / 4000.0 - 0.75).abs() < 0.03);
+}
+
+#[test]
+fn valued_symbols() {
+    let five = DieSymbol::new_valued(" Five ", 5).unwrap();
+    let pip_side = DieSide::new(vec![ pip(), pip() ]);
+    let five_side = DieSide::new(vec![ five.clone(), pip() ]);
+
+    assert_eq!(five.name(), "Five");
+    assert_eq!(five.value(), 5);
+    assert_eq!(pip().value(), 1);
+    assert_eq!(five, DieSymbol::new("Five").unwrap());
+    assert!(DieSymbol::new_valued(" ", 5).is_err());
+    assert_eq!(pip_side.total_value(), 2);
+    assert_eq!(five_side.total_value(), 6);
+
+    let die = Die::new(vec![ pip_side, five_side ]).unwrap();
+    assert_eq!(die.expected_value(), 4.0);
+    assert_eq!(die.average_of(&five), 0.5);
+    assert_eq!(die.shift_values(1).sides()[1].value(), Some(7));
+    assert_eq!(d6().expected_value(), 3.5);
+}
+
+#[test]
+fn parse_notation() {
+    let dice = notation::parse("3d6").unwrap();
+    assert_eq!(dice.len(), 3);
+    assert!(dice.iter().all(|d| d.sides().len() == 6));
+
+    let dice = notation::parse(" d20 + 1D4 - 3 + 5 ").unwrap();
+    assert_eq!(dice.len(), 3);
+    assert_eq!(dice[0].sides().len(), 20);
+    assert_eq!(dice[1].sides().len(), 4);
+    assert_eq!(dice[2].expected_value(), 2.0);
+    assert_eq!(dice[2].average_of(&pip()), 2.0);
+    assert_eq!(notation::parse("1d6-2").unwrap_err(), "a negative modifier can't be shown as pips in '1d6-2', use parse_roll to keep it as an offset");
+    assert_eq!(notation::parse_roll("1d6-2").unwrap().modifier(), -2);
+
+    let dice = notation::parse("2d4+2").unwrap();
+    assert_eq!(dice[2].average_of(&pip()), 2.0);
+}
+
+#[test]
+fn parse_notation_keep_highest() {
+    let roll = notation::parse_roll("4d6k3").unwrap();
+    assert_eq!(roll.dice().len(), 4);
+    assert_eq!(roll.keep_highest(), Some(3));
+    assert_eq!(roll.modifier(), 0);
+
+    let roll = notation::parse_roll("2d8-1+4").unwrap();
+    assert_eq!(roll.keep_highest(), None);
+    assert_eq!(roll.modifier(), 3);
+}
+
+#[test]
+fn parse_malformed_notation() {
+    for input in [ "", "d", "3d", "3d1", "0d6", "d6+", "3d6k", "xd6", "2d6d6", "5", "-1d6", "2d6k1+1d4", "3d6++2" ] {
+        assert!(notation::parse(input).is_err(), "{} should be rejected", input);
+    }
+    assert_eq!(notation::parse("3d").unwrap_err(), "missing number of sides in '3d'");
+}
+
+#[test]
+fn parse_notation_rejects_bad_keep_and_modifier() {
+    assert_eq!(notation::parse_roll("4d6k0").err().unwrap(), "must keep between 1 and 4 dice in '4d6k0'");
+    assert_eq!(notation::parse_roll("4d6k9").err().unwrap(), "must keep between 1 and 4 dice in '4d6k9'");
+    assert_eq!(notation::parse_roll("4d6k4").unwrap().keep_highest(), Some(4));
+    assert_eq!(notation::parse("1d20+1000000000").unwrap_err(), "modifier must be between -1000 and 1000 in '1d20+1000000000'");
+    assert!(notation::parse_roll("1d20-600-600").is_err());
+    assert_eq!(notation::parse_roll("1d20+1000").unwrap().modifier(), 1000);
+    assert_eq!(notation::parse_roll("1d20+600-600").unwrap().modifier(), 0);
+}
+
+#[test]
+fn parse_notation_rejects_too_many_dice_or_sides() {
+    assert_eq!(notation::parse_roll("1d100000").err().unwrap(), "dice cannot have more than 1000 sides in '1d100000'");
+    assert_eq!(notation::parse_roll("99999999d6").err().unwrap(), "cannot roll more than 100 dice in '99999999d6'");
+    assert_eq!(notation::parse_roll("60d6 + 41d4").err().unwrap(), "cannot roll more than 100 dice in '60d6 + 41d4'");
+    assert_eq!(notation::parse_roll("60d6 + 40d4").unwrap().dice().len(), 100);
+    assert_eq!(notation::parse_roll("1d1000").unwrap().dice()[0].sides().len(), 1000);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_round_trip() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let five = DieSymbol::new_valued("Five", 5).unwrap();
+    let custom_d4 = Die::new(vec![
+        DieSide::new(vec![ sword.clone(), sword.clone() ]),
+        DieSide::new(vec![ sword, shield.clone() ]),
+        DieSide::with_value(vec![ five ], 2),
+        DieSide::new(vec![ ]) ]).unwrap();
+
+    let json = serde_json::to_string(&custom_d4).unwrap();
+    assert_eq!(json, r#"{"sides":[{"symbols":["Sword","Sword"]},{"symbols":["Sword","Shield"]},{"symbols":[{"name":"Five","value":5}],"value":2},{"symbols":[]}]}"#);
+    let loaded: Die = serde_json::from_str(&json).unwrap();
+    assert_eq!(format!("{:?}", loaded), format!("{:?}", custom_d4));
+
+    let loaded_coin = Die::new_weighted(vec![ (DieSide::new(vec![ shield ]), 3), (DieSide::new(vec![]), 1) ]).unwrap();
+    let json = serde_json::to_string(&loaded_coin).unwrap();
+    let loaded: Die = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.weights(), &[3, 1]);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_rejects_invalid_dice() {
+    assert!(serde_json::from_str::<Die>(r#"{"sides":[{"symbols":["Sword"]}]}"#).is_err());
+    assert!(serde_json::from_str::<Die>(r#"{"sides":[{"symbols":[]},{"symbols":[]}],"weights":[1]}"#).is_err());
+    assert!(serde_json::from_str::<Die>(r#"{"sides":[{"symbols":[]},{"symbols":[]}],"weights":[1,0]}"#).is_err());
+    assert!(serde_json::from_str::<DieSymbol>(r#""   ""#).is_err());
+    assert_eq!(serde_json::from_str::<DieSymbol>(r#"" Pip ""#).unwrap(), pip());
 }
\ No newline at end of file
@@ -1,47 +1,229 @@
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::iter::FromIterator;
 use std::hash::{Hash, Hasher};
 
-#[derive(PartialEq, Eq, Clone)]
-pub struct ItemCounter<T: Hash + PartialEq + Eq + PartialOrd + Ord + Clone> {
+#[derive(PartialEq, Eq, Clone, Debug)]
+/// Counts the number of occurrences of each distinct item added to it
+pub struct ItemCounter<T: Hash + PartialEq + Eq + Clone> {
     items: HashMap<T, usize>
 }
 
-impl<T: Hash + PartialEq + Eq + PartialOrd + Ord + Clone> Hash for ItemCounter<T> {
+impl<T: Hash + PartialEq + Eq + Clone> Hash for ItemCounter<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut items: Vec<T> = self.items.keys().cloned().collect();
-        items.sort();
-        for item in items {
-            for _ in 0..(self.items[&item]) {
-                item.hash(state);
-            }
+        // the map's order isn't stable, so hash the items in the order of their own hashes
+        let mut items: Vec<(u64, usize)> = 
+            self.items.iter()
+            .map(|(item, count)| {
+                let mut hasher = DefaultHasher::new();
+                item.hash(&mut hasher);
+                (hasher.finish(), *count)
+            })
+            .collect();
+        items.sort_unstable();
+        items.hash(state);
+    }
+}
+
+impl<T: Hash + PartialEq + Eq + Clone> Default for ItemCounter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + PartialEq + Eq + Clone> FromIterator<T> for ItemCounter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        for item in iter {
+            counter.add(&item);
         }
+        counter
     }
 }
 
-impl<T: Hash + PartialEq + Eq + PartialOrd + Ord + Clone> ItemCounter<T> {
+impl<T: Hash + PartialEq + Eq + Clone> ItemCounter<T> {
+    /// Creates a new, empty [`ItemCounter`](crate::item_counter::ItemCounter)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let counter: ItemCounter<&str> = ItemCounter::new();
+    /// 
+    /// assert_eq!(counter.total_count(), 0);
+    /// ```
     pub fn new() -> ItemCounter<T> {
         ItemCounter {
             items: HashMap::new()
         }
     }
 
+    /// Creates a new [`ItemCounter`](crate::item_counter::ItemCounter) with one occurrence of each item in the slice
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let counter = ItemCounter::from_slice(&[ "Pip", "Pip", "Blank" ]);
+    /// 
+    /// assert_eq!(counter.get_count(&"Pip"), 2);
+    /// assert_eq!(counter.get_count(&"Blank"), 1);
+    /// ```
+    pub fn from_slice(items: &[T]) -> ItemCounter<T> {
+        items.iter().cloned().collect()
+    }
+
+    /// Adds a single occurrence of the item
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// 
+    /// counter.add(&"Pip");
+    /// 
+    /// assert_eq!(counter.get_count(&"Pip"), 1);
+    /// ```
     pub fn add(&mut self, item: &T) {
         self.add_amount(item, 1)
     }
 
+    /// Adds the given number of occurrences of the item
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// 
+    /// counter.add_amount(&"Pip", 3);
+    /// 
+    /// assert_eq!(counter.get_count(&"Pip"), 3);
+    /// ```
     pub fn add_amount(&mut self, item: &T, amount: usize) {
-        if self.items.contains_key(item) {
-            self.items.get_mut(item).map(|x| *x += amount);
-        } else {
-            self.items.insert(item.clone(), amount);
+        *self.items.entry(item.clone()).or_insert(0) += amount;
+    }
+
+    /// Removes a single occurrence of the item, forgetting the item entirely once none are left. Does nothing if the item has no occurrences
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// counter.add_amount(&"Pip", 2);
+    /// 
+    /// counter.remove(&"Pip");
+    /// 
+    /// assert_eq!(counter.get_count(&"Pip"), 1);
+    /// ```
+    pub fn remove(&mut self, item: &T) {
+        self.sub_amount(item, 1)
+    }
+
+    /// Removes the given number of occurrences of the item, stopping at `0`. The item is forgotten entirely once none are left, 
+    /// so the counter matches one the item was never added to
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// counter.add_amount(&"Pip", 2);
+    /// 
+    /// counter.sub_amount(&"Pip", 5);
+    /// 
+    /// assert_eq!(counter.get_count(&"Pip"), 0);
+    /// assert_eq!(counter, ItemCounter::new());
+    /// ```
+    pub fn sub_amount(&mut self, item: &T, amount: usize) {
+        if let Some(count) = self.items.get_mut(item) {
+            *count = count.saturating_sub(amount);
+            if *count == 0 {
+                self.items.remove(item);
+            }
         }
     }
 
+    /// Returns the number of occurrences of the item, or `0` if it was never added
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// counter.add(&"Pip");
+    /// 
+    /// assert_eq!(counter.get_count(&"Pip"), 1);
+    /// assert_eq!(counter.get_count(&"Blank"), 0);
+    /// ```
     pub fn get_count(&self, item: &T) -> usize {
         *self.items.get(item).unwrap_or(&0)
     }
 
+    /// Returns the number of occurrences of all items combined
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// counter.add(&"Sword");
+    /// counter.add_amount(&"Shield", 2);
+    /// 
+    /// assert_eq!(counter.total_count(), 3);
+    /// ```
     pub fn total_count(&self) -> usize {
         self.items.values().sum()
     }
+
+    /// Returns the number of distinct items with at least one occurrence
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// counter.add(&"Sword");
+    /// counter.add_amount(&"Shield", 2);
+    /// 
+    /// assert_eq!(counter.distinct_count(), 2);
+    /// ```
+    pub fn distinct_count(&self) -> usize {
+        self.items.values().filter(|&&n| n > 0).count()
+    }
+
+    /// Returns an iterator over each distinct item and its number of occurrences, in no particular order
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut counter = ItemCounter::new();
+    /// counter.add(&"Sword");
+    /// counter.add_amount(&"Shield", 2);
+    /// 
+    /// let mut counts: Vec<(&&str, usize)> = counter.iter().collect();
+    /// counts.sort();
+    /// 
+    /// assert_eq!(counts, vec![ (&"Shield", 2), (&"Sword", 1) ]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.items.iter().map(|(item, count)| (item, *count))
+    }
+
+    /// Creates a new [`ItemCounter`](crate::item_counter::ItemCounter) holding the occurrences of both counters added together
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use art_dice::item_counter::ItemCounter;
+    /// let mut first = ItemCounter::new();
+    /// first.add_amount(&"Sword", 2);
+    /// let mut second = ItemCounter::new();
+    /// second.add(&"Sword");
+    /// second.add(&"Shield");
+    /// 
+    /// let merged = first.merge(&second);
+    /// 
+    /// assert_eq!(merged.get_count(&"Sword"), 3);
+    /// assert_eq!(merged.get_count(&"Shield"), 1);
+    /// ```
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        for (item, count) in other.iter() {
+            merged.add_amount(item, count);
+        }
+        merged
+    }
 }
\ No newline at end of file
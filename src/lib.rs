@@ -1,3 +1,3 @@
 pub mod dice;
 pub mod rolls;
-mod item_counter;
\ No newline at end of file
+pub mod item_counter;
\ No newline at end of file
@@ -1,313 +1,3101 @@
 use itertools::Itertools;
-use std::collections::HashMap;
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use crate::dice::*;
 use crate::item_counter::ItemCounter;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Eq, PartialEq, Clone, Hash)]
-struct RollResultPossibility {
-    symbols: ItemCounter<DieSymbol>
+#[derive(Eq, PartialEq, Clone, Hash, Debug)]
+/// Represents one distinct result of a roll, as the number of each [`DieSymbol`](crate::dice::DieSymbol) collected. 
+/// A result built by [`new_totals_only`](crate::rolls::RollProbabilities::new_totals_only) holds only the total of the tracked symbols rather than the symbols themselves, 
+/// and reports it from [`count_of`](crate::rolls::RollOutcome::count_of), [`total_count`](crate::rolls::RollOutcome::total_count) and [`total_value`](crate::rolls::RollOutcome::total_value). 
+/// Only [`total_value`](crate::rolls::RollOutcome::total_value) can report a total below zero; the counts report it as `0`
+pub struct RollOutcome {
+    symbols: ItemCounter<DieSymbol>,
+    offset: i64
 }
 
-impl RollResultPossibility {
-    pub fn new() -> RollResultPossibility {
-        RollResultPossibility {
-            symbols: ItemCounter::new()
+impl RollOutcome {
+    fn new() -> RollOutcome {
+        RollOutcome {
+            symbols: ItemCounter::new(),
+            offset: 0
         }
     }
 
-    pub fn add_symbols(&self, symbols: &[DieSymbol]) -> RollResultPossibility {
-        let mut symbol_count = self.clone().symbols;
-        for symbol in symbols {
-            symbol_count.add(symbol);
+    fn from_symbols(symbols: ItemCounter<DieSymbol>) -> RollOutcome {
+        RollOutcome { symbols, offset: 0 }
+    }
+
+    fn from_total(total: i64) -> RollOutcome {
+        RollOutcome { symbols: ItemCounter::new(), offset: total }
+    }
+
+    fn add_symbols(&self, symbols: &[DieSymbol]) -> RollOutcome {
+        RollOutcome { 
+            symbols: self.symbols.merge(&ItemCounter::from_slice(symbols)), 
+            offset: self.offset 
+        }
+    }
+
+    fn combine(&self, other: &RollOutcome) -> RollOutcome {
+        RollOutcome { 
+            symbols: self.symbols.merge(&other.symbols), 
+            offset: self.offset + other.offset 
+        }
+    }
+
+    fn signed_total_count(&self) -> i64 {
+        self.symbols.total_count() as i64 + self.offset
+    }
+
+    fn longest_run(&self) -> usize {
+        let values: Vec<i64> = 
+            self.symbols.iter()
+            .map(|(symbol, _)| symbol.value())
+            .sorted()
+            .dedup()
+            .collect();
+        let mut longest = 0;
+        let mut current = 0;
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 && values[i - 1] + 1 == *value {
+                current += 1;
+            } else {
+                current = 1;
+            }
+            longest = longest.max(current);
         }
-        RollResultPossibility { symbols: symbol_count }
+        longest
+    }
+
+    /// Returns the number of times the [`DieSymbol`](crate::dice::DieSymbol) was collected in this result, or `0` if it wasn't collected
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let shield = DieSymbol::new("Shield")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone(), sword.clone(), shield.clone() ]), DieSide::new(vec![]) ])?;
+    /// let symbols = vec![ sword.clone(), shield.clone() ];
+    /// let results = RollProbabilities::new(&[die], &RollCollectionPolicy::collect_all(&symbols))?;
+    /// 
+    /// let (outcome, _) = results.outcomes_sorted_by_count(&symbols)[1];
+    /// 
+    /// assert_eq!(outcome.symbol_count(&sword), 2);
+    /// assert_eq!(outcome.symbol_count(&shield), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symbol_count(&self, symbol: &DieSymbol) -> usize {
+        self.symbols.get_count(symbol)
     }
 
+    /// Returns the total number of [`DieSymbols`](crate::dice::DieSymbol) collected in this result
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let shield = DieSymbol::new("Shield")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone(), sword.clone(), shield.clone() ]), DieSide::new(vec![]) ])?;
+    /// let symbols = vec![ sword.clone(), shield.clone() ];
+    /// let results = RollProbabilities::new(&[die], &RollCollectionPolicy::collect_all(&symbols))?;
+    /// 
+    /// let (outcome, _) = results.outcomes_sorted_by_count(&symbols)[1];
+    /// 
+    /// assert_eq!(outcome.total_count(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn total_count(&self) -> usize {
-        self.symbols.total_count()
+        usize::try_from(self.signed_total_count()).unwrap_or(0)
+    }
+
+    /// Returns the sum of the [`values`](crate::dice::DieSymbol::value) of every [`DieSymbol`](crate::dice::DieSymbol) collected in this result
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let shield = DieSymbol::new("Shield")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone(), sword.clone(), shield.clone() ]), DieSide::new(vec![]) ])?;
+    /// let symbols = vec![ sword.clone(), shield.clone() ];
+    /// let results = RollProbabilities::new(&[die], &RollCollectionPolicy::collect_all(&symbols))?;
+    /// 
+    /// let (outcome, _) = results.outcomes_sorted_by_count(&symbols)[1];
+    /// 
+    /// assert_eq!(outcome.total_value(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_value(&self) -> i64 {
+        self.symbols.iter()
+            .map(|(symbol, count)| symbol.value() * count as i64)
+            .sum::<i64>() + self.offset
+    }
+
+    /// Returns the number of times any of the provided [`DieSymbols`](crate::dice::DieSymbol) were collected in this result
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let shield = DieSymbol::new("Shield")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone(), sword.clone(), shield.clone() ]), DieSide::new(vec![]) ])?;
+    /// let symbols = vec![ sword.clone(), shield.clone() ];
+    /// let results = RollProbabilities::new(&[die], &RollCollectionPolicy::collect_all(&symbols))?;
+    /// 
+    /// let (outcome, _) = results.outcomes_sorted_by_count(&symbols)[1];
+    /// 
+    /// assert_eq!(outcome.count_of(&[ sword ]), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_of(&self, symbols: &[DieSymbol]) -> usize {
+        usize::try_from(self.signed_count_of(symbols)).unwrap_or(0)
+    }
+
+    fn signed_count_of(&self, symbols: &[DieSymbol]) -> i64 {
+        symbols.iter()
+            .map(|s| self.symbols.get_count(s) as i64)
+            .sum::<i64>() + self.offset
     }
 }
 
 /// Represents the type of targets for a given roll
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 enum RollTargetTypes {
     Exactly,
     AtLeast,
-    AtMost
+    AtMost,
+    Between(usize),
+    WeightedAtLeast(i64),
+    SetOf,
+    DistinctAtLeast
 }
  
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
-/// Represents the target for a given roll
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+/// Represents the target for a given roll. Targets borrow the provided symbols, 
+/// use [`into_owned`](crate::rolls::RollTarget::into_owned) to keep a target independently of them
 pub struct RollTarget<'a> {
     target_type: RollTargetTypes,
     amount: usize,
-    symbols: &'a [DieSymbol]
+    symbols: &'a [DieSymbol],
+    weights: &'a [(DieSymbol, i64)],
+    negated: bool
 }
 
 impl<'a> RollTarget<'a> {
     /// Returns an instance of a target that is exactly N of provided symbols
-    pub fn exactly_n_of(n: usize, symbols: &'a [DieSymbol]) -> RollTarget {
+    pub fn exactly_n_of(n: usize, symbols: &'a [DieSymbol]) -> RollTarget<'a> {
         RollTarget {
             target_type: RollTargetTypes::Exactly,
             amount: n,
-            symbols
+            symbols,
+            weights: &[],
+            negated: false
         }
     }
     /// Returns an instance of a target that is at least N of provided symbols
-    pub fn at_least_n_of(n: usize, symbols: &'a [DieSymbol]) -> RollTarget {
+    pub fn at_least_n_of(n: usize, symbols: &'a [DieSymbol]) -> RollTarget<'a> {
         RollTarget {
             target_type: RollTargetTypes::AtLeast,
             amount: n,
-            symbols
+            symbols,
+            weights: &[],
+            negated: false
         }
     }
     /// Returns an instance of a target that is at most N of provided symbols
-    pub fn at_most_n_of(n: usize, symbols: &'a [DieSymbol]) -> RollTarget {
+    pub fn at_most_n_of(n: usize, symbols: &'a [DieSymbol]) -> RollTarget<'a> {
         RollTarget {
             target_type: RollTargetTypes::AtMost,
             amount: n,
-            symbols
+            symbols,
+            weights: &[],
+            negated: false
+        }
+    }
+
+    /// Returns an instance of a target that is between low and high of provided symbols, inclusive. Matches nothing if low is greater than high
+    pub fn between(low: usize, high: usize, symbols: &'a [DieSymbol]) -> RollTarget<'a> {
+        RollTarget {
+            target_type: RollTargetTypes::Between(high),
+            amount: low,
+            symbols,
+            weights: &[],
+            negated: false
+        }
+    }
+
+    /// Returns an instance of a target where the sum of each provided symbol's count multiplied by its weight is at least N. 
+    /// Weights may be negative to represent symbols that cancel others out
+    pub fn weighted_at_least(n: i64, weights: &'a [(DieSymbol, i64)]) -> RollTarget<'a> {
+        RollTarget {
+            target_type: RollTargetTypes::WeightedAtLeast(n),
+            amount: 0,
+            symbols: &[],
+            weights,
+            negated: false
+        }
+    }
+
+    /// Returns an instance of a target where any single one of the provided symbols occurs at least K times, such as a three of a kind
+    pub fn has_set_of(k: usize, symbols: &'a [DieSymbol]) -> RollTarget<'a> {
+        RollTarget {
+            target_type: RollTargetTypes::SetOf,
+            amount: k,
+            symbols,
+            weights: &[],
+            negated: false
+        }
+    }
+
+    /// Returns an instance of a target where at least K different symbols of the provided symbols occur at least once each
+    pub fn has_n_distinct(k: usize, symbols: &'a [DieSymbol]) -> RollTarget<'a> {
+        RollTarget {
+            target_type: RollTargetTypes::DistinctAtLeast,
+            amount: k,
+            symbols,
+            weights: &[],
+            negated: false
+        }
+    }
+
+    /// Returns an instance of a target that is met exactly when this target is not, such as not exactly N of the provided symbols
+    pub fn negate(self) -> RollTarget<'a> {
+        RollTarget {
+            negated: !self.negated,
+            ..self
+        }
+    }
+
+    /// Returns an [`OwnedRollTarget`](crate::rolls::OwnedRollTarget) holding a copy of the target's symbols, so it can be stored and reused without borrowing them
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{OwnedRollTarget, RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let target: OwnedRollTarget = {
+    ///     let symbols = vec![ standard::pip() ];
+    ///     RollTarget::at_least_n_of(4, &symbols).into_owned()
+    /// };
+    /// let symbols = vec![ standard::pip() ];
+    /// let results = RollProbabilities::new(&[ standard::d6() ], &RollCollectionPolicy::collect_all(&symbols))?;
+    /// 
+    /// assert_eq!(results.get_odds(&[ target.as_target() ]), 0.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_owned(self) -> OwnedRollTarget {
+        OwnedRollTarget {
+            target_type: self.target_type,
+            amount: self.amount,
+            symbols: self.symbols.to_vec(),
+            weights: self.weights.to_vec(),
+            negated: self.negated
+        }
+    }
+
+    fn boosted_symbols(&self) -> impl Iterator<Item = &DieSymbol> {
+        self.symbols.iter()
+            .chain(self.weights.iter().filter(|(_, weight)| *weight > 0).map(|(symbol, _)| symbol))
+    }
+
+    fn is_met_by(&self, poss: &RollOutcome) -> bool {
+        let count = poss.signed_count_of(self.symbols);
+        let amount = self.amount as i64;
+        let met = match self.target_type {
+            RollTargetTypes::Exactly => count == amount,
+            RollTargetTypes::AtLeast => count >= amount,
+            RollTargetTypes::AtMost => count <= amount,
+            RollTargetTypes::Between(high) => amount <= count && count <= high as i64,
+            RollTargetTypes::WeightedAtLeast(n) => 
+                self.weights.iter()
+                .map(|(symbol, weight)| (poss.symbols.get_count(symbol) as i64) * weight)
+                .sum::<i64>() >= n,
+            RollTargetTypes::SetOf => 
+                self.symbols.iter()
+                .any(|symbol| poss.symbols.get_count(symbol) >= self.amount),
+            RollTargetTypes::DistinctAtLeast => 
+                self.symbols.iter()
+                .unique()
+                .filter(|symbol| poss.symbols.get_count(symbol) > 0)
+                .count() >= self.amount
+        };
+        met != self.negated
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Represents a [`RollTarget`](crate::rolls::RollTarget) that owns its symbols, made with [`into_owned`](crate::rolls::RollTarget::into_owned), 
+/// so it can be kept in a collection and reused across rolls
+pub struct OwnedRollTarget {
+    target_type: RollTargetTypes,
+    amount: usize,
+    symbols: Vec<DieSymbol>,
+    weights: Vec<(DieSymbol, i64)>,
+    negated: bool
+}
+
+impl OwnedRollTarget {
+    /// Returns a [`RollTarget`](crate::rolls::RollTarget) borrowing this target's symbols, to check it against a roll
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let owned = RollTarget::exactly_n_of(6, &symbols).into_owned();
+    /// let results = RollProbabilities::new(&[ standard::d6() ], &RollCollectionPolicy::collect_all(&symbols))?;
+    /// 
+    /// assert_eq!(owned.as_target(), RollTarget::exactly_n_of(6, &symbols));
+    /// assert_eq!(results.get_odds(&[ owned.as_target() ]), 1.0 / 6.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_target(&self) -> RollTarget<'_> {
+        RollTarget {
+            target_type: self.target_type,
+            amount: self.amount,
+            symbols: &self.symbols,
+            weights: &self.weights,
+            negated: self.negated
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+/// Represents the comparison used when checking a roll against a threshold
+pub enum ThresholdOp {
+    /// The roll must be strictly greater than the threshold
+    Gt,
+    /// The roll must be greater than or equal to the threshold
+    Ge,
+    /// The roll must be equal to the threshold
+    Eq,
+    /// The roll must be less than or equal to the threshold
+    Le,
+    /// The roll must be strictly less than the threshold
+    Lt
+}
+
+impl ThresholdOp {
+    fn matches(&self, value: i64, threshold: i64) -> bool {
+        match self {
+            ThresholdOp::Gt => value > threshold,
+            ThresholdOp::Ge => value >= threshold,
+            ThresholdOp::Eq => value == threshold,
+            ThresholdOp::Le => value <= threshold,
+            ThresholdOp::Lt => value < threshold
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+/// Represents a change to how each [`Die`](crate::dice::Die) in a roll is resolved
+pub enum RollModifier<'a> {
+    /// A side showing every symbol in `on`, counting repeats, is kept and the die is rolled again, adding the new side's symbols. 
+    /// Each die explodes at most `max_depth` times; a side that would explode past that depth is simply kept
+    Explode { on: &'a [DieSymbol], max_depth: usize }
+}
+
+impl<'a> RollModifier<'a> {
+    fn side_triggers(on: &[DieSymbol], side: &DieSide) -> bool {
+        on.iter().unique().all(|symbol| 
+            side.count_of(symbol) >= on.iter().filter(|s| *s == symbol).count())
+    }
+
+    fn weighted_sides(&self, die: &Die) -> Result<Vec<(DieSide, usize)>, String> {
+        match *self {
+            RollModifier::Explode { on, max_depth } => Self::exploded_sides(die, on, max_depth)
         }
     }
+
+    fn exploded_sides(die: &Die, on: &[DieSymbol], depth_left: usize) -> Result<Vec<(DieSide, usize)>, String> {
+        let mut weighted = Vec::new();
+        for (side, side_weight) in die.weighted_sides() {
+            if depth_left > 0 && Self::side_triggers(on, side) {
+                for (next, weight) in Self::exploded_sides(die, on, depth_left - 1)? {
+                    let symbols = side.symbols().iter().chain(next.symbols()).cloned().collect();
+                    let exploded = match (side.value(), next.value()) {
+                        (None, None) => DieSide::new(symbols),
+                        (a, b) => DieSide::with_value(symbols, 
+                            a.unwrap_or_else(|| side.total_value()) + 
+                            b.unwrap_or_else(|| next.total_value()))
+                    };
+                    let weight = side_weight.checked_mul(weight).ok_or_else(RollProbabilities::combinations_overflow)?;
+                    weighted.push((exploded, weight));
+                }
+            } else {
+                let weight = 
+                    u32::try_from(depth_left).ok()
+                    .and_then(|depth| die.total_weight().checked_pow(depth))
+                    .and_then(|rerolls| side_weight.checked_mul(rerolls))
+                    .ok_or_else(RollProbabilities::combinations_overflow)?;
+                weighted.push((side.clone(), weight));
+            }
+        }
+        Ok(weighted)
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
-enum RollCollectionTypes {
+enum RollCollectionTypes<'a> {
     CollectAll,
     TakeHighestN(usize),
+    TakeHighestDistinctN(usize),
+    TakeHighestByValueN(usize),
     TakeLowestN(usize),
     RemoveHighestN(usize),
-    RemoveLowestN(usize)
+    RemoveLowestN(usize),
+    DropShowing(&'a [DieSymbol]),
+    CollectAbove(usize),
+    CountSuccesses(usize),
+    CountSuccessesWithBotch(usize, &'a [DieSymbol])
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
 /// Defines the policy used to collect dice after a roll based on [`DieSymbol`](crate::dice::DieSymbol) occurrences
 pub struct RollCollectionPolicy<'a> {
-    coll_type: RollCollectionTypes,
+    coll_type: RollCollectionTypes<'a>,
     symbols: &'a [DieSymbol]
 }
 
 impl<'a> RollCollectionPolicy<'a> {
     /// Policy for collecting all dice in the roll
-    pub fn collect_all(symbols: &'a [DieSymbol]) -> RollCollectionPolicy {
+    pub fn collect_all(symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
         RollCollectionPolicy {
             coll_type: RollCollectionTypes::CollectAll,
             symbols
         }
     }
 
-    /// Policy for taking the highest N dice, ordering by number of matching symbols
-    pub fn take_highest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy {
+    /// Policy for taking the highest N dice, ordering by number of matching symbols. Every die is taken if N is at least the pool size
+    pub fn take_highest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
         RollCollectionPolicy {
             coll_type: RollCollectionTypes::TakeHighestN(n),
             symbols
         }
     }
 
-    /// Policy for taking the lowest N dice, ordering by number of matching symbols
-    pub fn take_lowest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy {
+    /// Policy for taking the highest N dice, ordering by number of distinct matching symbols. Every die is taken if N is at least the pool size
+    pub fn take_highest_distinct_n(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::TakeHighestDistinctN(n),
+            symbols
+        }
+    }
+
+    /// Policy for taking the highest N dice, ordering by the summed [`value`](crate::dice::DieSymbol::value) of the matching symbols. Every die is taken if N is at least the pool size
+    pub fn take_highest_n_by_value(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::TakeHighestByValueN(n),
+            symbols
+        }
+    }
+
+    /// Policy for taking the lowest N dice, ordering by number of matching symbols. Every die is taken if N is at least the pool size
+    pub fn take_lowest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
         RollCollectionPolicy {
             coll_type: RollCollectionTypes::TakeLowestN(n),
             symbols
         }
     }
-    
-    /// Policy for removing the highest N dice and collecting the rest, ordering by number of matching symbols
-    pub fn remove_highest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy {
-        RollCollectionPolicy {
-            coll_type: RollCollectionTypes::RemoveHighestN(n),
-            symbols
+    
+    /// Policy for removing the highest N dice and collecting the rest, ordering by number of matching symbols. Nothing is collected if N is at least the pool size
+    pub fn remove_highest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::RemoveHighestN(n),
+            symbols
+        }
+    }
+    
+    /// Policy for removing the lowest N dice and collecting the rest, ordering by number of matching symbols. Nothing is collected if N is at least the pool size
+    pub fn remove_lowest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::RemoveLowestN(n),
+            symbols
+        }
+    }
+
+    /// Policy for discarding any die showing at least one of the `drop` symbols and collecting the rest
+    pub fn drop_showing(drop: &'a [DieSymbol], symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::DropShowing(drop),
+            symbols
+        }
+    }
+
+    /// Policy for collecting the matching symbols of each die showing at least `threshold` of them, discarding the dice that show fewer. 
+    /// Unlike taking the highest N dice, the number of dice kept depends on the roll
+    pub fn collect_above(threshold: usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::CollectAbove(threshold),
+            symbols
+        }
+    }
+
+    /// Policy for counting successes, where each die showing at least `threshold` matching symbols contributes a single 
+    /// [`success_symbol`](crate::rolls::RollCollectionPolicy::success_symbol) instead of its symbols. Each die is judged on its own sides, 
+    /// so in a pool of differently sided dice the larger dice are more likely to succeed against the same threshold
+    pub fn count_successes(threshold: usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::CountSuccesses(threshold),
+            symbols
+        }
+    }
+
+    /// Policy for counting net successes like [`count_successes`](crate::rolls::RollCollectionPolicy::count_successes), 
+    /// where each die showing at least one of the `botch` symbols cancels one success. When the botches outnumber the successes, 
+    /// one [`botch_symbol`](crate::rolls::RollCollectionPolicy::botch_symbol) is collected per excess botch instead, so a botch is told apart 
+    /// from a roll without successes. A die that both succeeds and botches cancels itself out
+    pub fn count_successes_with_botch(success_threshold: usize, botch: &'a [DieSymbol], symbols: &'a [DieSymbol]) -> RollCollectionPolicy<'a> {
+        RollCollectionPolicy {
+            coll_type: RollCollectionTypes::CountSuccessesWithBotch(success_threshold, botch),
+            symbols
+        }
+    }
+
+    /// Returns the symbol collected for each successful die by [`count_successes`](crate::rolls::RollCollectionPolicy::count_successes)
+    pub fn success_symbol() -> DieSymbol {
+        DieSymbol::new("Success").unwrap()
+    }
+
+    /// Returns the symbol collected for each botch left uncancelled by [`count_successes_with_botch`](crate::rolls::RollCollectionPolicy::count_successes_with_botch), 
+    /// which has a [`value`](crate::dice::DieSymbol::value) of `-1`
+    pub fn botch_symbol() -> DieSymbol {
+        DieSymbol::new_valued("Botched", -1).unwrap()
+    }
+
+    /// Collects the [`DieSymbols`](crate::dice::DieSymbol) kept by this policy from a concrete roll, 
+    /// using the same logic as [`RollProbabilities`](crate::rolls::RollProbabilities) uses for each possible roll
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RollCollectionPolicy;
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::take_highest_n_of(1, &symbols);
+    /// let d6 = standard::d6();
+    /// let roll = vec![ &d6.sides()[1], &d6.sides()[4] ];
+    /// 
+    /// assert_eq!(policy.apply(&roll), vec![ standard::pip(); 5 ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply(&self, roll: &[&DieSide]) -> Vec<DieSymbol> {
+        let mut filtered_sides: Vec<Vec<DieSymbol>> =
+            roll.iter()
+            .filter(|x| match self.coll_type {
+                RollCollectionTypes::DropShowing(drop) => !drop.iter().any(|y| x.contains(y)),
+                _ => true
+            })
+            .map(|x| 
+                x.symbols().iter()
+                .filter(|y| self.symbols.contains(y))
+                .cloned().collect())
+            .collect();
+        match self.coll_type {
+            RollCollectionTypes::TakeHighestDistinctN(_) => filtered_sides.sort_by_key(|x| x.iter().unique().count()),
+            RollCollectionTypes::TakeHighestByValueN(_) => filtered_sides.sort_by_key(|x| x.iter().map(|y| y.value()).sum::<i64>()),
+            _ => filtered_sides.sort_by_key(|x| x.len())
+        }
+        filtered_sides.reverse();
+        let sides_len = filtered_sides.len();
+        match self.coll_type {
+            RollCollectionTypes::CollectAll | RollCollectionTypes::DropShowing(_) => 
+                filtered_sides.iter()
+                .flatten().cloned().collect(),
+            RollCollectionTypes::TakeHighestN(n) | RollCollectionTypes::TakeHighestDistinctN(n) | RollCollectionTypes::TakeHighestByValueN(n) => 
+                filtered_sides.iter().take(n)
+                .flatten().cloned().collect(),
+            RollCollectionTypes::TakeLowestN(n) => 
+                filtered_sides.iter().skip(sides_len.saturating_sub(n))
+                .flatten().cloned().collect(),
+            RollCollectionTypes::RemoveHighestN(n) =>
+                filtered_sides.iter().skip(n)
+                .flatten().cloned().collect(),
+            RollCollectionTypes::RemoveLowestN(n) =>
+                filtered_sides.iter().take(sides_len.saturating_sub(n))
+                .flatten().cloned().collect(),
+            RollCollectionTypes::CollectAbove(threshold) => 
+                filtered_sides.iter()
+                .filter(|x| x.len() >= threshold)
+                .flatten().cloned().collect(),
+            RollCollectionTypes::CountSuccesses(threshold) => 
+                filtered_sides.iter()
+                .filter(|x| x.len() >= threshold)
+                .map(|_| RollCollectionPolicy::success_symbol())
+                .collect(),
+            RollCollectionTypes::CountSuccessesWithBotch(threshold, botch) => {
+                let successes = filtered_sides.iter().filter(|x| x.len() >= threshold).count();
+                let botches = roll.iter().filter(|x| botch.iter().any(|y| x.contains(y))).count();
+                if botches > successes {
+                    vec![ RollCollectionPolicy::botch_symbol(); botches - successes ]
+                } else {
+                    vec![ RollCollectionPolicy::success_symbol(); successes - botches ]
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+/// Defines which [`DieSides`](crate::dice::DieSide) are rerolled before the dice are collected. 
+/// Each die is rerolled using its own sides, and only once, keeping the new result. The reroll happens before any 
+/// [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) is applied, so a policy such as 
+/// [`take_highest_n_of`](crate::rolls::RollCollectionPolicy::take_highest_n_of) ranks the dice by their final sides
+pub struct RollRerollPolicy<'a> {
+    threshold: usize,
+    symbols: &'a [DieSymbol]
+}
+
+impl<'a> RollRerollPolicy<'a> {
+    /// Policy for rerolling each die showing fewer than N of the provided symbols, once
+    pub fn once_below(n: usize, symbols: &'a [DieSymbol]) -> RollRerollPolicy<'a> {
+        RollRerollPolicy {
+            threshold: n,
+            symbols
+        }
+    }
+
+    fn weighted_sides(&self, die: &Die) -> Vec<(DieSide, usize)> {
+        let mut weighted = Vec::new();
+        for (side, side_weight) in die.weighted_sides() {
+            let matching = side.symbols().iter().filter(|s| self.symbols.contains(s)).count();
+            if matching >= self.threshold {
+                weighted.push((side.clone(), side_weight * die.total_weight()));
+            } else {
+                weighted.extend(die.weighted_sides().map(|(reroll, weight)| (reroll.clone(), side_weight * weight)));
+            }
+        }
+        weighted
+    }
+}
+
+/// Tracks the probabilities of a roll of one or more dice
+pub struct RollProbabilities {
+    occurrences: HashMap<RollOutcome, usize>,
+    total: usize
+}
+
+impl Default for RollProbabilities {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl RollProbabilities {
+    /// Creates an empty instance of [`RollProbabilities`](crate::rolls::RollProbabilities), representing no roll. 
+    /// All odds retrieved from an empty instance are `0.0`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// 
+    /// let empty = RollProbabilities::empty();
+    /// 
+    /// assert_eq!(empty.get_odds(&[RollTarget::at_least_n_of(0, &symbols)]), 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn empty() -> RollProbabilities {
+        RollProbabilities {
+            occurrences: HashMap::new(),
+            total: 0
+        }
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) based on the provided collection of [`Dice`](crate::dice::Die). 
+    /// Die sides are collected based on the provided [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy). 
+    /// Returns `Err` if provided slice contains no elements, any die has fewer than 2 sides, none of the dice show any of the policy's symbols, 
+    /// or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ] ;
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let dice = vec![standard::d4(), standard::d4()];
+    /// 
+    /// let two_d4s = RollProbabilities::new(&dice, &policy)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(dice: &[Die], policy: &RollCollectionPolicy) -> Result<RollProbabilities, String> {
+        Self::check_dice(dice.iter(), policy)?;
+        let weighted_sides: Vec<Vec<(&DieSide, usize)>> = 
+            dice.iter()
+            .map(|x| x.weighted_sides().collect())
+            .collect();
+        Self::from_weighted_sides(&weighted_sides, policy)
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) like [`new`](crate::rolls::RollProbabilities::new), 
+    /// where each [`Die`](crate::dice::Die) is paired with the number of times it is rolled, such as `(standard::d6(), 3)` for 3d6. 
+    /// Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new), any count is `0` or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// 
+    /// let three_d6 = RollProbabilities::new_counted(&[(standard::d6(), 3)], &policy)?;
+    /// 
+    /// assert_eq!(three_d6.get_odds(&[RollTarget::exactly_n_of(18, &symbols)]), 1.0 / 216.0);
+    /// assert!(RollProbabilities::new_counted(&[(standard::d6(), 0)], &policy).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_counted(dice: &[(Die, usize)], policy: &RollCollectionPolicy) -> Result<RollProbabilities, String> {
+        Self::check_dice(dice.iter().map(|(die, _)| die), policy)?;
+        if dice.iter().any(|(_, count)| *count == 0) {
+            return Err("must roll each die at least once".to_string());
+        }
+        let weighted_sides: Vec<Vec<(&DieSide, usize)>> = 
+            dice.iter()
+            .flat_map(|(die, count)| (0..*count).map(move |_| die.weighted_sides().collect()))
+            .collect();
+        Self::from_weighted_sides(&weighted_sides, policy)
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) like [`new`](crate::rolls::RollProbabilities::new), 
+    /// but resolves each [`Die`](crate::dice::Die) with the provided [`RollModifier`](crate::rolls::RollModifier) first. 
+    /// The symbols from all of one die's sides count as a single die for the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy), 
+    /// and each result is weighted by its probability. Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new) 
+    /// or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy, RollModifier};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let max_face = vec![ standard::pip(); 4 ];
+    /// let explode = RollModifier::Explode { on: &max_face, max_depth: 1 };
+    /// 
+    /// let exploding_d4 = RollProbabilities::new_with_roll_modifier(&[standard::d4()], &policy, &explode)?;
+    /// 
+    /// assert_eq!(exploding_d4.get_odds(&[RollTarget::exactly_n_of(4, &symbols)]), 0.0);
+    /// assert_eq!(exploding_d4.get_odds(&[RollTarget::at_least_n_of(5, &symbols)]), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_roll_modifier(dice: &[Die], policy: &RollCollectionPolicy, modifier: &RollModifier) -> Result<RollProbabilities, String> {
+        Self::check_dice(dice.iter(), policy)?;
+        let weighted_sides: Vec<Vec<(DieSide, usize)>> = 
+            dice.iter()
+            .map(|die| modifier.weighted_sides(die))
+            .collect::<Result<_, _>>()?;
+        Self::from_weighted_sides(&Self::borrow_weighted_sides(&weighted_sides), policy)
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) like [`new`](crate::rolls::RollProbabilities::new), 
+    /// but first rerolls the dice chosen by the provided [`RollRerollPolicy`](crate::rolls::RollRerollPolicy), each using its own sides. 
+    /// The final sides are then collected with the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy). 
+    /// Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new) or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy, RollRerollPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let reroll = RollRerollPolicy::once_below(3, &symbols);
+    /// 
+    /// let rerolled = RollProbabilities::new_with_reroll(&[standard::d4()], &policy, &reroll)?;
+    /// 
+    /// assert_eq!(rerolled.get_odds(&[RollTarget::at_least_n_of(3, &symbols)]), 0.75);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_reroll(dice: &[Die], policy: &RollCollectionPolicy, reroll: &RollRerollPolicy) -> Result<RollProbabilities, String> {
+        Self::check_dice(dice.iter(), policy)?;
+        let weighted_sides: Vec<Vec<(DieSide, usize)>> = 
+            dice.iter()
+            .map(|die| reroll.weighted_sides(die))
+            .collect();
+        Self::from_weighted_sides(&Self::borrow_weighted_sides(&weighted_sides), policy)
+    }
+
+    fn check_dice<'d, I: Iterator<Item = &'d Die> + Clone>(dice: I, policy: &RollCollectionPolicy) -> Result<(), String> {
+        if dice.clone().next().is_none() {
+            return Err("must include at least one die".to_string());
+        }
+        if dice.clone().any(|d| d.sides().len() < 2) {
+            return Err("every die must have at least 2 sides".to_string());
+        }
+        if !dice.clone().any(|d| d.sides().iter().any(|side| policy.symbols.iter().any(|s| side.contains(s)))) {
+            return Err("none of the dice show any of the policy's symbols".to_string());
+        }
+        Ok(())
+    }
+
+    fn borrow_weighted_sides(weighted_sides: &[Vec<(DieSide, usize)>]) -> Vec<Vec<(&DieSide, usize)>> {
+        weighted_sides.iter()
+            .map(|x| x.iter().map(|(side, weight)| (side, *weight)).collect())
+            .collect()
+    }
+
+    fn same_weighted_sides(a: &[(&DieSide, usize)], b: &[(&DieSide, usize)]) -> bool {
+        a.len() == b.len() &&
+            a.iter().zip(b.iter())
+            .all(|((side_a, weight_a), (side_b, weight_b))| 
+                weight_a == weight_b && side_a.symbols() == side_b.symbols() && side_a.value() == side_b.value())
+    }
+
+    fn multinomial(counts: &[usize]) -> usize {
+        let mut result = 1;
+        let mut n = 0;
+        for &count in counts {
+            for j in 1..=count {
+                n += 1;
+                result = (result as u128 * n as u128 / j as u128) as usize;
+            }
+        }
+        result
+    }
+
+    fn grouped_occurrences(sides: &[(&DieSide, usize)], dice_count: usize, policy: &RollCollectionPolicy) -> HashMap<RollOutcome, usize> {
+        let side_results: Vec<RollOutcome> = 
+            sides.iter()
+            .map(|(side, _)| RollOutcome::new().add_symbols(&policy.apply(&[side])))
+            .collect();
+        let mut occur = HashMap::new();
+        for indices in (0..sides.len()).combinations_with_replacement(dice_count) {
+            let counts: Vec<usize> = 
+                indices.iter()
+                .dedup_with_count()
+                .map(|(count, _)| count)
+                .collect();
+            let weight: usize = indices.iter().map(|&i| sides[i].1).product();
+            let poss = 
+                indices.iter()
+                .fold(RollOutcome::new(), |acc, &i| acc.combine(&side_results[i]));
+            *occur.entry(poss).or_insert(0) += weight * Self::multinomial(&counts);
+        }
+        occur
+    }
+
+    fn combinations_overflow() -> String {
+        "too many combinations to count without overflowing".to_string()
+    }
+
+    fn check_combinations<I: Iterator<Item = usize>>(mut die_weights: I) -> Result<(), String> {
+        match die_weights.try_fold(1usize, |acc, w| acc.checked_mul(w)) {
+            Some(_) => Ok(()),
+            None => Err(Self::combinations_overflow())
+        }
+    }
+
+    fn check_weighted_sides(weighted_sides: &[Vec<(&DieSide, usize)>]) -> Result<(), String> {
+        let die_weights: Vec<usize> = 
+            weighted_sides.iter()
+            .map(|x| x.iter().try_fold(0usize, |acc, (_, w)| acc.checked_add(*w)))
+            .collect::<Option<_>>()
+            .ok_or_else(Self::combinations_overflow)?;
+        Self::check_combinations(die_weights.into_iter())
+    }
+
+    fn from_weighted_sides(weighted_sides: &[Vec<(&DieSide, usize)>], policy: &RollCollectionPolicy) -> Result<RollProbabilities, String> {
+        Self::check_weighted_sides(weighted_sides)?;
+        let mut occur = HashMap::new();
+        Self::for_each_outcome(weighted_sides, policy, |poss, weight| *occur.entry(poss).or_insert(0) += weight);
+        let total = occur.values().sum();
+        Ok(RollProbabilities {
+            occurrences: occur,
+            total
+        })
+    }
+
+    fn for_each_outcome<F: FnMut(RollOutcome, usize)>(weighted_sides: &[Vec<(&DieSide, usize)>], policy: &RollCollectionPolicy, mut add: F) {
+        match policy.coll_type {
+            RollCollectionTypes::CollectAll | RollCollectionTypes::DropShowing(_) | 
+            RollCollectionTypes::CollectAbove(_) | RollCollectionTypes::CountSuccesses(_) => {
+                // each die is collected on its own, so identical dice only need each multiset of sides once
+                let mut groups: Vec<(&[(&DieSide, usize)], usize)> = Vec::new();
+                for sides in weighted_sides.iter() {
+                    match groups.iter_mut().find(|(group, _)| Self::same_weighted_sides(group, sides)) {
+                        Some((_, count)) => *count += 1,
+                        None => groups.push((sides, 1))
+                    }
+                }
+                let grouped: Vec<HashMap<RollOutcome, usize>> = 
+                    groups.iter()
+                    .map(|(sides, count)| Self::grouped_occurrences(sides, *count, policy))
+                    .collect();
+                for roll in grouped.iter()
+                        .map(|x| x.iter())
+                        .multi_cartesian_product() {
+                    let poss = roll.iter().fold(RollOutcome::new(), |acc, (poss, _)| acc.combine(poss));
+                    let weight: usize = roll.iter().map(|(_, w)| *w).product();
+                    add(poss, weight);
+                }
+            },
+            _ => {
+                for roll in weighted_sides.iter()
+                        .map(|x| x.iter())
+                        .multi_cartesian_product() {
+                    let sides: Vec<&DieSide> = roll.iter().map(|(side, _)| *side).collect();
+                    let collected = policy.apply(&sides);
+                    let weight: usize = roll.iter().map(|(_, w)| w).product();
+                    add(RollOutcome::new().add_symbols(&collected), weight);
+                }
+            }
+        }
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) like [`new`](crate::rolls::RollProbabilities::new), 
+    /// but only enumerates every combination of sides when there are at most `max_combinations` of them. 
+    /// Otherwise it rolls the pool `max_combinations` times and builds the probabilities from those samples, so the work done is bounded by the budget either way. 
+    /// The returned [`RollEstimate`](crate::rolls::RollEstimate) is `Exact` or `Approximate` depending on which path was taken. 
+    /// Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new) or the budget is `0`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollEstimate, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let small_pool = vec![ standard::d6(), standard::d6() ];
+    /// let large_pool = vec![ standard::d20(); 10 ];
+    /// 
+    /// let small = RollProbabilities::new_with_budget(&small_pool, &policy, 10000, &mut rand::thread_rng())?;
+    /// let large = RollProbabilities::new_with_budget(&large_pool, &policy, 10000, &mut rand::thread_rng())?;
+    /// 
+    /// assert!(small.is_exact());
+    /// assert!(!large.is_exact());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn new_with_budget<R: Rng>(dice: &[Die], policy: &RollCollectionPolicy, max_combinations: usize, rng: &mut R) -> Result<RollEstimate, String> {
+        Self::check_dice(dice.iter(), policy)?;
+        if max_combinations == 0 {
+            return Err("budget must allow at least one combination".to_string());
+        }
+        let combinations = 
+            dice.iter()
+            .try_fold(1usize, |acc, d| acc.checked_mul(d.sides().len()));
+        match combinations {
+            Some(n) if n <= max_combinations => Ok(RollEstimate::Exact(Self::new(dice, policy)?)),
+            _ => {
+                let mut occur = HashMap::new();
+                for _ in 0..max_combinations {
+                    let pool = RolledPool::roll(dice, rng);
+                    let roll: Vec<&DieSide> = pool.sides().iter().collect();
+                    let collected = policy.apply(&roll);
+                    let new_poss = RollOutcome::new().add_symbols(&collected);
+                    *occur.entry(new_poss).or_insert(0) += 1;
+                }
+                Ok(RollEstimate::Approximate(RollProbabilities {
+                    occurrences: occur,
+                    total: max_combinations
+                }))
+            }
+        }
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) which only tracks the total number of the provided 
+    /// [`DieSymbols`](crate::dice::DieSymbol), using far less memory than [`new`](crate::rolls::RollProbabilities::new) for dice with many symbols. 
+    /// The result must only be queried with that same group of symbols: queries over any other group, or a subset of the group, will be incorrect. 
+    /// Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new) or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let hit = DieSymbol::new("Hit")?;
+    /// let crit = DieSymbol::new("Crit")?;
+    /// let die = Die::new(vec![ 
+    ///     DieSide::new(vec![ hit.clone() ]), 
+    ///     DieSide::new(vec![ crit.clone() ]), 
+    ///     DieSide::new(vec![]) ])?;
+    /// let symbols = vec![ hit, crit ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// 
+    /// let totals = RollProbabilities::new_totals_only(&[die.clone(), die], &policy, &symbols)?;
+    /// 
+    /// assert_eq!(totals.get_odds(&[RollTarget::exactly_n_of(2, &symbols)]), 4.0/9.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_totals_only(dice: &[Die], policy: &RollCollectionPolicy, symbols: &[DieSymbol]) -> Result<RollProbabilities, String> {
+        Self::check_dice(dice.iter(), policy)?;
+        let weighted_sides: Vec<Vec<(&DieSide, usize)>> = 
+            dice.iter()
+            .map(|x| x.weighted_sides().collect())
+            .collect();
+        Self::check_weighted_sides(&weighted_sides)?;
+        let mut totals: BTreeMap<i64, usize> = BTreeMap::new();
+        Self::for_each_outcome(&weighted_sides, policy, |poss, weight| 
+            *totals.entry(poss.count_of(symbols) as i64).or_insert(0) += weight);
+        Ok(Self::from_totals(totals))
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) like [`new_totals_only`](crate::rolls::RollProbabilities::new_totals_only), 
+    /// adding `modifier` to the total of the provided [`DieSymbols`](crate::dice::DieSymbol) whenever the rolled dice show at least `trigger_at_least` 
+    /// of the `trigger` symbols. Triggers are checked against every rolled side, before the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) is applied. 
+    /// A total taken below zero stays signed: targets, [`mean`](crate::rolls::RollProbabilities::mean) and 
+    /// [`value_distribution`](crate::rolls::RollProbabilities::value_distribution) see it as negative, while [`distribution`](crate::rolls::RollProbabilities::distribution) 
+    /// leaves it out. The result must only be queried with the same group of symbols. Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new) 
+    /// or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let curse = DieSymbol::new("Curse")?;
+    /// let curse_die = Die::new(vec![ DieSide::new(vec![ curse.clone() ]), DieSide::new(vec![]) ])?;
+    /// let trigger = vec![ curse ];
+    /// 
+    /// let cursed = RollProbabilities::new_with_conditional_modifier(
+    ///     &[standard::d6(), curse_die], &policy, &trigger, 1, -2, &symbols)?;
+    /// 
+    /// assert_eq!(cursed.get_odds(&[RollTarget::exactly_n_of(6, &symbols)]), 1.0 / 12.0);
+    /// assert_eq!(cursed.get_odds(&[RollTarget::exactly_n_of(0, &symbols)]), 1.0 / 12.0);
+    /// assert_eq!(cursed.get_odds(&[RollTarget::at_most_n_of(0, &symbols)]), 2.0 / 12.0);
+    /// assert_eq!(cursed.value_distribution()[0], (-1, 1.0 / 12.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_conditional_modifier(
+            dice: &[Die], 
+            policy: &RollCollectionPolicy, 
+            trigger: &[DieSymbol], 
+            trigger_at_least: usize, 
+            modifier: i64, 
+            symbols: &[DieSymbol]) -> Result<RollProbabilities, String> {
+        Self::check_dice(dice.iter(), policy)?;
+        Self::check_combinations(dice.iter().map(|d| d.total_weight()))?;
+        let mut totals: BTreeMap<i64, usize> = BTreeMap::new();
+        for weighted_roll in dice.iter()
+                .map(|x| x.weighted_sides())
+                .multi_cartesian_product() {
+            let (roll, weights): (Vec<&DieSide>, Vec<usize>) = weighted_roll.into_iter().unzip();
+            let triggers = 
+                roll.iter()
+                .flat_map(|side| side.symbols())
+                .filter(|s| trigger.contains(s))
+                .count();
+            let mut count = 
+                policy.apply(&roll).iter()
+                .filter(|s| symbols.contains(s))
+                .count() as i64;
+            if triggers >= trigger_at_least {
+                count += modifier;
+            }
+            *totals.entry(count).or_insert(0) += weights.iter().product::<usize>();
+        }
+        Ok(Self::from_totals(totals))
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) where every [`Die`](crate::dice::Die) that fails to show at least 
+    /// `success_threshold` of the `success_symbols` is rerolled once, keeping the new result. Every die's final side is collected for the provided 
+    /// [`DieSymbols`](crate::dice::DieSymbol). Returns `Err` if the dice would be rejected by [`new`](crate::rolls::RollProbabilities::new) 
+    /// or there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// 
+    /// let rerolled = RollProbabilities::new_reroll_failures(&[standard::d4()], &symbols, 3, &symbols)?;
+    /// 
+    /// assert_eq!(rerolled.get_odds(&[RollTarget::at_least_n_of(3, &symbols)]), 0.75);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_reroll_failures(dice: &[Die], success_symbols: &[DieSymbol], success_threshold: usize, symbols: &[DieSymbol]) -> Result<RollProbabilities, String> {
+        Self::new_with_reroll(
+            dice, 
+            &RollCollectionPolicy::collect_all(symbols), 
+            &RollRerollPolicy::once_below(success_threshold, success_symbols))
+    }
+
+    fn from_totals(totals: BTreeMap<i64, usize>) -> RollProbabilities {
+        let total = totals.values().sum();
+        let occur = 
+            totals.into_iter()
+            .map(|(count, occurrences)| (RollOutcome::from_total(count), occurrences))
+            .collect();
+        RollProbabilities {
+            occurrences: occur,
+            total
+        }
+    }
+
+    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) counting how many [`Dice`](crate::dice::Die) 
+    /// show any of the provided [`DieSymbols`](crate::dice::DieSymbol), with each die counting at most once no matter how many matching symbols it shows. 
+    /// This mirrors anydice.com's `[count {..} in ..]`. The result should be queried using the same `which` symbols. 
+    /// Returns `Err` if either provided slice contains no elements, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities};
+    /// # fn main() -> Result<(), String> {
+    /// let hit = DieSymbol::new("Hit")?;
+    /// let die = Die::new(vec![ 
+    ///     DieSide::new(vec![ hit.clone(), hit.clone() ]), 
+    ///     DieSide::new(vec![]) ])?;
+    /// let which = vec![ hit ];
+    /// 
+    /// let hits = RollProbabilities::count_in(&[die.clone(), die], &which)?;
+    /// 
+    /// assert_eq!(hits.get_odds(&[RollTarget::exactly_n_of(2, &which)]), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_in(dice: &[Die], which: &[DieSymbol]) -> Result<RollProbabilities, String> {
+        let marker = match which.first() {
+            Some(symbol) => symbol,
+            None => return Err("must include at least one symbol".to_string())
+        };
+        let counting_dice: Vec<Die> = 
+            dice.iter()
+            .map(|d| d.weighted_sides()
+                .map(|(side, weight)| 
+                    if side.symbols().iter().any(|s| which.contains(s)) { (DieSide::new(vec![ marker.clone() ]), weight) }
+                    else { (DieSide::new(vec![]), weight) })
+                .collect())
+            .map(|sides| Die::new_weighted(sides).unwrap())
+            .collect();
+        Self::new(&counting_dice, &RollCollectionPolicy::collect_all(which))
+    }
+
+    /// Combines two independent rolls into the probabilities of rolling both together, merging the symbols of every pair of results. 
+    /// This matches building a single roll from both pools when the collection policies act on each pool separately, 
+    /// without enumerating every die at once. Combining with an empty instance returns an empty instance. 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let attack = RollProbabilities::new(&[standard::d6(), standard::d6()], &policy)?;
+    /// let bonus = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let all_at_once = RollProbabilities::new(&[standard::d6(), standard::d6(), standard::d4()], &policy)?;
+    /// 
+    /// let combined = attack.combine(&bonus)?;
+    /// 
+    /// assert_eq!(combined.distribution(&symbols), all_at_once.distribution(&symbols));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn combine(&self, other: &Self) -> Result<RollProbabilities, String> {
+        let total = self.total.checked_mul(other.total).ok_or_else(Self::combinations_overflow)?;
+        let mut occur: HashMap<RollOutcome, usize> = HashMap::new();
+        for (poss, occurrences) in self.occurrences.iter() {
+            for (other_poss, other_occurrences) in other.occurrences.iter() {
+                // bounded by the total checked above
+                *occur.entry(poss.combine(other_poss)).or_insert(0) += occurrences * other_occurrences;
+            }
+        }
+        Ok(RollProbabilities {
+            occurrences: occur,
+            total
+        })
+    }
+
+    /// Retrieves the probability of the roll achieving all of the [`RollTargets`](crate::rolls::RollTarget). 
+    /// Note that the roll's [`DieSymbols`](crate::dice::DieSymbol) will have been filtered down based
+    /// on the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) used to generate the probability
+    /// 
+    /// # Examples
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let dice = vec![standard::d4(), standard::d4()];
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&dice, &policy)?;
+    /// 
+    /// let exactly_3 = two_d4s.get_odds(&vec![ RollTarget::exactly_n_of(3, &symbols)]);
+    /// let at_least_6 = two_d4s.get_odds(&vec![ RollTarget::at_least_n_of(6, &symbols)]);
+    /// let at_most_5 = two_d4s.get_odds(&vec![ RollTarget::at_most_n_of(5, &symbols)]);
+    /// 
+    /// assert_eq!(exactly_3, 0.125);
+    /// assert_eq!(at_least_6, 0.375);
+    /// assert_eq!(at_most_5, 0.625);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_odds(&self, targets: &[RollTarget]) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        let mut total_occurrences = 0;
+        for poss in self.occurrences.keys() {
+            if targets.iter().all(|t| t.is_met_by(poss)) {
+                total_occurrences += self.occurrences[poss];
+            }
+        }
+        (total_occurrences as f64) / (self.total as f64)
+    }
+
+    /// Retrieves the probability of the roll achieving at least one of the [`RollTargets`](crate::rolls::RollTarget). 
+    /// Each possible result is counted once, however many of the targets it meets. Returns `0.0` if no targets are provided.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let extremes = two_d4s.get_odds_any(&[ RollTarget::at_most_n_of(2, &symbols), RollTarget::at_least_n_of(8, &symbols) ]);
+    /// 
+    /// assert_eq!(extremes, 0.125);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_odds_any(&self, targets: &[RollTarget]) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        let total_occurrences: usize = 
+            self.occurrences.iter()
+            .filter(|(poss, _)| targets.iter().any(|t| t.is_met_by(poss)))
+            .map(|(_, occurrences)| occurrences)
+            .sum();
+        (total_occurrences as f64) / (self.total as f64)
+    }
+
+    /// Retrieves the probability of the roll collecting exactly the listed number of each [`DieSymbol`](crate::dice::DieSymbol) and nothing else. 
+    /// Unlike combining [`exactly_n_of`](crate::rolls::RollTarget::exactly_n_of) targets, a result that also collected any unlisted symbol doesn't match. 
+    /// Symbols outside the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) are never collected, so listing one with a nonzero count gives `0.0`. 
+    /// A symbol listed more than once uses the sum of its counts. Returns `0.0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let shield = DieSymbol::new("Shield")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![ shield.clone() ]) ])?;
+    /// let symbols = vec![ sword.clone(), shield.clone() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let results = RollProbabilities::new(&[die.clone(), die.clone(), die], &policy)?;
+    /// 
+    /// let two_swords_one_shield = results.odds_of_exact(&[ (sword.clone(), 2), (shield, 1) ]);
+    /// let only_two_swords = results.odds_of_exact(&[ (sword, 2) ]);
+    /// 
+    /// assert_eq!(two_swords_one_shield, 0.375);
+    /// assert_eq!(only_two_swords, 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn odds_of_exact(&self, outcome: &[(DieSymbol, usize)]) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let mut expected = ItemCounter::new();
+        for (symbol, count) in outcome.iter().filter(|(_, count)| *count > 0) {
+            expected.add_amount(symbol, *count);
+        }
+        let matching: usize = 
+            self.occurrences.iter()
+            .filter(|(poss, _)| poss.symbols == expected)
+            .map(|(_, occurrences)| occurrences)
+            .sum();
+        (matching as f64) / (self.total as f64)
+    }
+
+    /// Retrieves the probability of the roll collecting [`DieSymbols`](crate::dice::DieSymbol) with at least `length` consecutive 
+    /// [`values`](crate::dice::DieSymbol::value), such as a straight. Repeated values don't break a run and any gap in the values ends it. 
+    /// A `length` of 0 is always met. Returns `0.0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let faces: Vec<DieSymbol> = (1..=6).map(|n| DieSymbol::new_valued(n.to_string(), n)).collect::<Result<_, _>>()?;
+    /// let face_die = Die::new(faces.iter().map(|f| DieSide::new(vec![ f.clone() ])).collect())?;
+    /// let results = RollProbabilities::new(&vec![ face_die; 5 ], &RollCollectionPolicy::collect_all(&faces))?;
+    /// 
+    /// let large_straight = results.odds_of_run(5);
+    /// 
+    /// assert_eq!(large_straight, 240.0 / 7776.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn odds_of_run(&self, length: usize) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let matching: usize = 
+            self.occurrences.iter()
+            .filter(|(poss, _)| poss.longest_run() >= length)
+            .map(|(_, occurrences)| occurrences)
+            .sum();
+        (matching as f64) / (self.total as f64)
+    }
+
+    /// Retrieves the probability of the roll achieving all of the [`RollTargets`](crate::rolls::RollTarget) when every `wild` 
+    /// [`DieSymbol`](crate::dice::DieSymbol) can stand in for any one symbol used by the targets. For each outcome the wilds are assigned 
+    /// in whichever way meets the targets, if any such assignment exists, and a wild may also be left unused. The wild symbol must be collected 
+    /// by the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) and should not itself be used in the targets
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let wild = DieSymbol::new("Wild")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![ wild.clone() ]) ])?;
+    /// let swords = vec![ sword.clone() ];
+    /// let symbols = vec![ sword, wild.clone() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let results = RollProbabilities::new(&[die], &policy)?;
+    /// 
+    /// let odds = results.get_odds_with_wild(&[ RollTarget::at_least_n_of(1, &swords) ], &wild);
+    /// 
+    /// assert_eq!(odds, 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_odds_with_wild(&self, targets: &[RollTarget], wild: &DieSymbol) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        let candidates: Vec<DieSymbol> = 
+            targets.iter()
+            .flat_map(|t| t.boosted_symbols())
+            .filter(|s| *s != wild)
+            .unique()
+            .cloned()
+            .collect();
+        let mut total_occurrences = 0;
+        for (poss, occurrences) in self.occurrences.iter() {
+            let wilds = poss.symbols.get_count(wild);
+            let met = 
+                (0..=candidates.len())
+                .combinations_with_replacement(wilds)
+                .any(|assignment| {
+                    let assigned: Vec<DieSymbol> = 
+                        assignment.iter()
+                        .filter_map(|i| candidates.get(*i).cloned())
+                        .collect();
+                    let boosted = poss.add_symbols(&assigned);
+                    targets.iter().all(|t| t.is_met_by(&boosted))
+                });
+            if met {
+                total_occurrences += occurrences;
+            }
+        }
+        (total_occurrences as f64) / (self.total as f64)
+    }
+
+    /// Draws a single random result from the roll, weighting each possible result by its probability, and returns a copy of its 
+    /// [`DieSymbol`](crate::dice::DieSymbol) counts. Unlike rolling each die separately, the result reflects the 
+    /// [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) used to generate the probabilities. Returns an empty count if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::take_highest_n_of(1, &symbols);
+    /// let best_of_two = RollProbabilities::new(&[standard::d6(), standard::d6()], &policy)?;
+    /// 
+    /// let sample = best_of_two.sample(&mut rand::thread_rng());
+    /// 
+    /// assert!((1..=6).contains(&sample.get_count(&standard::pip())));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> ItemCounter<DieSymbol> {
+        if self.total == 0 {
+            return ItemCounter::new();
+        }
+        let mut remaining = rng.gen_range(0..self.total);
+        for (poss, occurrences) in self.occurrences.iter() {
+            if remaining < *occurrences {
+                return poss.symbols.clone();
+            }
+            remaining -= occurrences;
+        }
+        ItemCounter::new()
+    }
+
+    /// Retrieves the probability of the number of matching [`DieSymbols`](crate::dice::DieSymbol) in the roll 
+    /// comparing to the threshold using the provided [`ThresholdOp`](crate::rolls::ThresholdOp). 
+    /// Returns `0.0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy, ThresholdOp};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d20 = RollProbabilities::new(&[standard::d20()], &policy)?;
+    /// 
+    /// let meets_dc_15 = d20.meets_threshold(15, ThresholdOp::Ge, &symbols);
+    /// let beats_dc_15 = d20.meets_threshold(15, ThresholdOp::Gt, &symbols);
+    /// 
+    /// assert_eq!(meets_dc_15, 0.3);
+    /// assert_eq!(beats_dc_15, 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn meets_threshold(&self, threshold: usize, op: ThresholdOp, symbols: &[DieSymbol]) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let matching: usize = 
+            self.occurrences.iter()
+            .filter(|(poss, _)| op.matches(poss.signed_count_of(symbols), threshold as i64))
+            .map(|(_, occurrences)| occurrences)
+            .sum();
+        (matching as f64) / (self.total as f64)
+    }
+
+    fn count_occurrences(&self, symbols: &[DieSymbol]) -> BTreeMap<usize, usize> {
+        self.signed_count_occurrences(symbols).into_iter()
+            .filter_map(|(count, occurrences)| usize::try_from(count).ok().map(|count| (count, occurrences)))
+            .collect()
+    }
+
+    fn signed_count_occurrences(&self, symbols: &[DieSymbol]) -> BTreeMap<i64, usize> {
+        let mut counts = BTreeMap::new();
+        for (poss, occurrences) in self.occurrences.iter() {
+            *counts.entry(poss.signed_count_of(symbols)).or_insert(0) += occurrences;
+        }
+        counts
+    }
+
+    fn count_moments(&self, symbols: &[DieSymbol]) -> (f64, f64) {
+        let total = self.total as f64;
+        let counts = self.signed_count_occurrences(symbols);
+        let mean: f64 = 
+            counts.iter()
+            .map(|(count, occurrences)| *count as f64 * *occurrences as f64 / total)
+            .sum();
+        let variance: f64 = 
+            counts.iter()
+            .map(|(count, occurrences)| (*count as f64 - mean).powi(2) * *occurrences as f64 / total)
+            .sum();
+        (mean, variance)
+    }
+
+    /// Returns an iterator over each distinct result of the roll, as a [`RollOutcome`](crate::rolls::RollOutcome) holding the count of every 
+    /// collected [`DieSymbol`](crate::dice::DieSymbol) paired with the probability of that result. The order of the results is not guaranteed; use 
+    /// [`outcomes_sorted_by_count`](crate::rolls::RollProbabilities::outcomes_sorted_by_count) for a fixed order.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let total: f64 = two_d4s.outcomes().map(|(_, p)| p).sum();
+    /// 
+    /// assert_eq!(two_d4s.outcomes().count(), 7);
+    /// assert_eq!(total, 1.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outcomes(&self) -> impl Iterator<Item = (&RollOutcome, f64)> + '_ {
+        let total = self.total as f64;
+        self.occurrences.iter()
+            .map(move |(poss, occurrences)| (poss, (*occurrences as f64) / total))
+    }
+
+    /// Returns each distinct result of the roll like [`outcomes`](crate::rolls::RollProbabilities::outcomes), sorted by the number of the 
+    /// provided [`DieSymbols`](crate::dice::DieSymbol) in ascending order. Results with the same number are in no particular order.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let outcomes = two_d4s.outcomes_sorted_by_count(&symbols);
+    /// 
+    /// assert_eq!(outcomes[0].0.symbol_count(&standard::pip()), 2);
+    /// assert_eq!(outcomes[0].1, 0.0625);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outcomes_sorted_by_count(&self, symbols: &[DieSymbol]) -> Vec<(&RollOutcome, f64)> {
+        let mut outcomes: Vec<(&RollOutcome, f64)> = self.outcomes().collect();
+        outcomes.sort_by_key(|(outcome, _)| outcome.signed_count_of(symbols));
+        outcomes
+    }
+
+    /// Returns the probability of each achievable number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll, 
+    /// as `(count, probability)` pairs sorted by count. Totals below zero from [`new_with_conditional_modifier`](crate::rolls::RollProbabilities::new_with_conditional_modifier) 
+    /// are left out; use [`value_distribution`](crate::rolls::RollProbabilities::value_distribution) to see them. Returns an empty `Vec` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let distribution = two_d4s.distribution(&symbols);
+    /// 
+    /// assert_eq!(distribution.len(), 7);
+    /// assert_eq!(distribution[0], (2, 0.0625));
+    /// assert_eq!(distribution[3], (5, 0.25));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn distribution(&self, symbols: &[DieSymbol]) -> Vec<(usize, f64)> {
+        let total = self.total as f64;
+        self.count_occurrences(symbols).into_iter()
+            .map(|(count, occurrences)| (count, (occurrences as f64) / total))
+            .collect()
+    }
+
+    /// Returns the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) 
+    /// as a map from each achievable count to its probability, ordered by count. The probabilities sum to `1.0`. 
+    /// Returns an empty map if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let distribution = two_d4s.to_distribution(&symbols);
+    /// 
+    /// assert_eq!(distribution.keys().next(), Some(&2));
+    /// assert_eq!(distribution[&5], 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_distribution(&self, symbols: &[DieSymbol]) -> BTreeMap<usize, f64> {
+        self.distribution(symbols).into_iter().collect()
+    }
+
+    /// Returns the probability of each achievable total value of the collected [`DieSymbols`](crate::dice::DieSymbol), 
+    /// where each symbol adds its [`value`](crate::dice::DieSymbol::value), as `(total value, probability)` pairs sorted by total. 
+    /// Returns an empty `Vec` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let one = DieSymbol::new("One")?;
+    /// let five = DieSymbol::new_valued("Five", 5)?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ one.clone() ]), DieSide::new(vec![ five.clone() ]) ])?;
+    /// let symbols = vec![ one, five ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let results = RollProbabilities::new(&[die.clone(), die], &policy)?;
+    /// 
+    /// let values = results.value_distribution();
+    /// 
+    /// assert_eq!(values, vec![ (2, 0.25), (6, 0.5), (10, 0.25) ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value_distribution(&self) -> Vec<(i64, f64)> {
+        let total = self.total as f64;
+        let mut values = BTreeMap::new();
+        for (poss, occurrences) in self.occurrences.iter() {
+            *values.entry(poss.total_value()).or_insert(0) += occurrences;
+        }
+        values.into_iter()
+            .map(|(value, occurrences)| (value, (occurrences as f64) / total))
+            .collect()
+    }
+
+    /// Returns the cumulative probability of each achievable number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll, 
+    /// as `(count, probability of at most count)` pairs sorted by count. Returns an empty `Vec` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let cumulative = two_d4s.cumulative(&symbols);
+    /// 
+    /// assert_eq!(cumulative[0], (2, 0.0625));
+    /// assert_eq!(cumulative[3], (5, 0.625));
+    /// assert_eq!(cumulative[6], (8, 1.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cumulative(&self, symbols: &[DieSymbol]) -> Vec<(usize, f64)> {
+        let total = self.total as f64;
+        let mut running = 0;
+        self.signed_count_occurrences(symbols).into_iter()
+            .filter_map(|(count, occurrences)| {
+                running += occurrences;
+                usize::try_from(count).ok().map(|count| (count, (running as f64) / total))
+            })
+            .collect()
+    }
+
+    /// Returns the smallest number of the provided [`DieSymbols`](crate::dice::DieSymbol) whose [`cumulative`](crate::rolls::RollProbabilities::cumulative) 
+    /// probability is at least `p`, so a roll is at most that count with probability `p`. A `p` of `0.0` or less gives the smallest achievable count 
+    /// and a `p` of `1.0` or more gives the largest. Returns `0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.percentile(&symbols, 0.5), 5);
+    /// assert_eq!(two_d4s.percentile(&symbols, 0.9), 7);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn percentile(&self, symbols: &[DieSymbol], p: f64) -> usize {
+        let cumulative = self.cumulative(symbols);
+        cumulative.iter()
+            .find(|(_, cp)| *cp >= p)
+            .or_else(|| cumulative.last())
+            .map(|(count, _)| *count)
+            .unwrap_or(0)
+    }
+
+    /// Returns the smallest achievable number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll. Returns `0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.min_count(&symbols), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn min_count(&self, symbols: &[DieSymbol]) -> usize {
+        self.count_occurrences(symbols).keys().next().copied().unwrap_or(0)
+    }
+
+    /// Returns the largest achievable number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll. Returns `0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.max_count(&symbols), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn max_count(&self, symbols: &[DieSymbol]) -> usize {
+        self.count_occurrences(symbols).keys().next_back().copied().unwrap_or(0)
+    }
+
+    /// Returns the most likely number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll, preferring the lowest count when several are equally likely. 
+    /// Returns `0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.mode(&symbols), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mode(&self, symbols: &[DieSymbol]) -> usize {
+        let mut mode = (0, 0);
+        for (count, occurrences) in self.count_occurrences(symbols) {
+            if occurrences > mode.1 {
+                mode = (count, occurrences);
+            }
+        }
+        mode.0
+    }
+
+    /// Returns the expected number of the provided [`DieSymbols`](crate::dice::DieSymbol) given that the roll has at least `threshold` of them, 
+    /// such as the average damage on a hit. Returns `0.0` if no outcome reaches the threshold.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let expected = two_d4s.tail_expectation(7, &symbols);
+    /// 
+    /// assert!((expected - 22.0 / 3.0).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tail_expectation(&self, threshold: usize, symbols: &[DieSymbol]) -> f64 {
+        let (weighted, mass) = 
+            self.distribution(symbols).into_iter()
+            .filter(|(count, _)| *count >= threshold)
+            .fold((0.0, 0.0), |(weighted, mass), (count, p)| (weighted + count as f64 * p, mass + p));
+        if mass == 0.0 {
+            return 0.0;
+        }
+        weighted / mass
+    }
+
+    /// Returns the expected number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll. Returns `0.0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.mean(&symbols), 5.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mean(&self, symbols: &[DieSymbol]) -> f64 {
+        self.count_moments(symbols).0
+    }
+
+    /// Returns the variance of the number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll. Returns `0.0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.variance(&symbols), 2.5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn variance(&self, symbols: &[DieSymbol]) -> f64 {
+        self.count_moments(symbols).1
+    }
+
+    /// Returns the standard deviation of the number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll, 
+    /// the square root of the [`variance`](crate::rolls::RollProbabilities::variance). Returns `0.0` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// assert_eq!(two_d4s.std_dev(&symbols), 2.5_f64.sqrt());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn std_dev(&self, symbols: &[DieSymbol]) -> f64 {
+        self.variance(symbols).sqrt()
+    }
+
+    /// Returns the coefficient of variation of the number of the provided [`DieSymbols`](crate::dice::DieSymbol), 
+    /// the standard deviation divided by the expected count. This compares the relative spread of pools with different averages. 
+    /// Returns `0.0` if the expected count is `0`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// 
+    /// let spread = d4.coefficient_of_variation(&symbols);
+    /// 
+    /// assert!((spread - 1.25_f64.sqrt() / 2.5).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn coefficient_of_variation(&self, symbols: &[DieSymbol]) -> f64 {
+        let (mean, variance) = self.count_moments(symbols);
+        if mean == 0.0 {
+            return 0.0;
+        }
+        variance.sqrt() / mean
+    }
+
+    /// Returns the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) 
+    /// with low-probability counts merged away for display. Every count with a probability of at least `min_prob` is retained, and every 
+    /// other count adds its probability to the nearest retained count, preferring the lower one when two are equally near. Counts below 
+    /// the lowest retained count therefore collect into it as a "this or less" bucket, and counts above the highest retained count 
+    /// collect into it as a "this or more" bucket. If no count is retained, all of the probability is merged into the most likely count, 
+    /// preferring the lowest. The probabilities still sum to the same total. Returns an empty `Vec` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let trimmed = two_d4s.trim_tails(&symbols, 0.1);
+    /// 
+    /// assert_eq!(trimmed.len(), 5);
+    /// assert_eq!(trimmed[0], (3, 0.1875));
+    /// assert_eq!(trimmed[4], (7, 0.1875));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trim_tails(&self, symbols: &[DieSymbol], min_prob: f64) -> Vec<(usize, f64)> {
+        let distribution = self.distribution(symbols);
+        let mut retained: Vec<usize> = 
+            distribution.iter()
+            .filter(|(_, p)| *p >= min_prob)
+            .map(|(count, _)| *count)
+            .collect();
+        if distribution.is_empty() {
+            return vec![];
+        }
+        if retained.is_empty() {
+            retained.push(self.mode(symbols));
+        }
+        let mut merged = BTreeMap::new();
+        for (count, p) in distribution {
+            let nearest = 
+                retained.iter()
+                .min_by_key(|r| (r.abs_diff(count), **r))
+                .copied()
+                .unwrap_or(count);
+            *merged.entry(nearest).or_insert(0.0) += p;
+        }
+        merged.into_iter().collect()
+    }
+
+    /// Returns the probability of each achievable number of distinct [`DieSymbols`](crate::dice::DieSymbol) appearing in the roll, 
+    /// as `(distinct count, probability)` pairs sorted by distinct count. Returns an empty `Vec` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let sword = DieSymbol::new("Sword")?;
+    /// let shield = DieSymbol::new("Shield")?;
+    /// let die = Die::new(vec![ DieSide::new(vec![ sword.clone() ]), DieSide::new(vec![ shield.clone() ]) ])?;
+    /// let symbols = vec![ sword, shield ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let results = RollProbabilities::new(&[die.clone(), die], &policy)?;
+    /// 
+    /// let distinct = results.distinct_count_distribution();
+    /// 
+    /// assert_eq!(distinct, vec![ (1, 0.5), (2, 0.5) ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn distinct_count_distribution(&self) -> Vec<(usize, f64)> {
+        let mut counts = BTreeMap::new();
+        for (poss, occurrences) in self.occurrences.iter() {
+            *counts.entry(poss.symbols.distinct_count()).or_insert(0) += occurrences;
+        }
+        let total = self.total as f64;
+        counts.into_iter()
+            .map(|(count, occurrences)| (count, (occurrences as f64) / total))
+            .collect()
+    }
+
+    /// Blends the [`distributions`](crate::rolls::RollProbabilities::distribution) of two rolls for the provided [`DieSymbols`](crate::dice::DieSymbol), 
+    /// returning `(1 - t) * a + t * b` for each count achievable by either roll, sorted by count. `t` is clamped between `0.0` and `1.0`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let d8 = RollProbabilities::new(&[standard::d8()], &policy)?;
+    /// 
+    /// let halfway = RollProbabilities::lerp(&d4, &d8, 0.5, &symbols);
+    /// 
+    /// assert_eq!(halfway.len(), 8);
+    /// assert_eq!(halfway[0], (1, 0.1875));
+    /// assert_eq!(halfway[7], (8, 0.0625));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lerp(a: &Self, b: &Self, t: f64, symbols: &[DieSymbol]) -> Vec<(usize, f64)> {
+        let t = t.clamp(0.0, 1.0);
+        let mut blended = BTreeMap::new();
+        for (count, p) in a.distribution(symbols) {
+            *blended.entry(count).or_insert(0.0) += (1.0 - t) * p;
+        }
+        for (count, p) in b.distribution(symbols) {
+            *blended.entry(count).or_insert(0.0) += t * p;
+        }
+        blended.into_iter().collect()
+    }
+
+    /// Compares the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) 
+    /// against a reference distribution, such as one exported from anydice.com. The reference is a slice of `(count, probability)` pairs 
+    /// with probabilities as decimals, and any count missing from either side is treated as having probability `0.0`. 
+    /// Returns the absolute difference for each count where it exceeds `epsilon`, sorted by count, or an empty `Vec` if all counts match.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let reference = vec![ (1, 0.25), (2, 0.25), (3, 0.25), (4, 0.25) ];
+    /// 
+    /// let differences = d4.compare_to_reference(&reference, &symbols, 0.0001);
+    /// 
+    /// assert!(differences.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compare_to_reference(&self, reference: &[(usize, f64)], symbols: &[DieSymbol], epsilon: f64) -> Vec<(usize, f64)> {
+        let mut differences: BTreeMap<usize, f64> = 
+            self.distribution(symbols).into_iter()
+            .collect();
+        for (count, expected) in reference {
+            *differences.entry(*count).or_insert(0.0) -= expected;
+        }
+        differences.into_iter()
+            .map(|(count, diff)| (count, diff.abs()))
+            .filter(|(_, diff)| *diff > epsilon)
+            .collect()
+    }
+
+    /// Returns every achievable number of the provided [`DieSymbols`](crate::dice::DieSymbol) in the roll, sorted ascending
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let totals = two_d4s.achievable_totals(&symbols);
+    /// 
+    /// assert_eq!(totals, vec![ 2, 3, 4, 5, 6, 7, 8 ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn achievable_totals(&self, symbols: &[DieSymbol]) -> Vec<usize> {
+        self.occurrences.keys()
+            .map(|poss| poss.count_of(symbols))
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// Renders the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) as JSON, 
+    /// in the form `{"total": N, "distribution": [{"count": c, "probability": p, "occurrences": o}, ...]}` sorted by count. 
+    /// Each entry includes the exact number of occurrences out of `total` alongside the probability.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// 
+    /// let json = d4.to_json(&symbols);
+    /// 
+    /// assert!(json.starts_with(r#"{"total":4,"distribution":[{"count":1,"probability":0.25,"occurrences":1},"#));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self, symbols: &[DieSymbol]) -> String {
+        #[derive(Serialize)]
+        struct JsonEntry {
+            count: usize,
+            probability: f64,
+            occurrences: usize
+        }
+        #[derive(Serialize)]
+        struct JsonDistribution {
+            total: usize,
+            distribution: Vec<JsonEntry>
+        }
+        let distribution = 
+            self.count_occurrences(symbols).into_iter()
+            .map(|(count, occurrences)| JsonEntry {
+                count,
+                probability: (occurrences as f64) / (self.total as f64),
+                occurrences
+            })
+            .collect();
+        serde_json::to_string(&JsonDistribution { total: self.total, distribution }).unwrap()
+    }
+
+    /// Renders the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) as CSV, 
+    /// with a `count,probability,occurrences` header followed by one row per achievable count, sorted by count.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// 
+    /// let csv = d4.to_csv(&symbols);
+    /// 
+    /// assert_eq!(csv, "count,probability,occurrences\n1,0.25,1\n2,0.25,1\n3,0.25,1\n4,0.25,1\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_csv(&self, symbols: &[DieSymbol]) -> String {
+        let mut csv = String::from("count,probability,occurrences\n");
+        for (count, occurrences) in self.count_occurrences(symbols) {
+            let probability = (occurrences as f64) / (self.total as f64);
+            csv.push_str(&format!("{},{},{}\n", count, probability, occurrences));
+        }
+        csv
+    }
+
+    /// Renders the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) 
+    /// as a single line of block characters, one per achievable count, scaled to the most likely count. 
+    /// Returns an empty `String` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let sparkline = two_d4s.to_sparkline(&symbols);
+    /// 
+    /// assert_eq!(sparkline, "▂▄▆█▆▄▂");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_sparkline(&self, symbols: &[DieSymbol]) -> String {
+        const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let distribution = self.distribution(symbols);
+        let peak = distribution.iter()
+            .map(|(_, p)| *p)
+            .fold(0.0, f64::max);
+        distribution.iter()
+            .map(|(_, p)| BLOCKS[((p / peak) * 8.0).ceil() as usize - 1])
+            .collect()
+    }
+
+    /// Renders the [`distribution`](crate::rolls::RollProbabilities::distribution) of the provided [`DieSymbols`](crate::dice::DieSymbol) 
+    /// as a text histogram with one line per achievable count. Each line has the right-aligned count, a bar of `#` scaled so the most likely 
+    /// count fills `width` characters, and the probability as a percentage. Returns an empty `String` if the struct is empty.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let histogram = two_d4s.render_histogram(&symbols, 8);
+    /// 
+    /// assert_eq!(histogram.lines().next(), Some("2 | ##         6.25%"));
+    /// assert_eq!(histogram.lines().nth(3), Some("5 | ########  25.00%"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_histogram(&self, symbols: &[DieSymbol], width: usize) -> String {
+        let distribution = self.distribution(symbols);
+        let peak = distribution.iter()
+            .map(|(_, p)| *p)
+            .fold(0.0, f64::max);
+        let label_width = distribution.iter()
+            .map(|(count, _)| count.to_string().len())
+            .max()
+            .unwrap_or(0);
+        distribution.iter()
+            .map(|(count, p)| {
+                let bar = "#".repeat(((p / peak) * width as f64).round() as usize);
+                format!("{:>lw$} | {:<w$} {:>6.2}%", count, bar, p * 100.0, lw = label_width, w = width)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn relabel<F: Fn(&RollOutcome) -> RollOutcome>(&self, f: F) -> RollProbabilities {
+        let mut occur = HashMap::new();
+        for (poss, occurrences) in self.occurrences.iter() {
+            *occur.entry(f(poss)).or_insert(0) += occurrences;
+        }
+        RollProbabilities {
+            occurrences: occur,
+            total: self.total
+        }
+    }
+
+    /// Creates a new [`RollProbabilities`](crate::rolls::RollProbabilities) where every result with more than `max` of the provided 
+    /// [`DieSymbols`](crate::dice::DieSymbol) is relabeled to have exactly `max` of them, so the higher results merge into the cap. 
+    /// The extra symbols are removed starting from the last provided symbol, and other symbols are left unchanged. No results are dropped.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d6s = RollProbabilities::new(&[standard::d6(), standard::d6()], &policy)?;
+    /// 
+    /// let capped = two_d6s.clamp(&symbols, 10);
+    /// 
+    /// assert_eq!(capped.get_odds(&[RollTarget::exactly_n_of(10, &symbols)]), 6.0 / 36.0);
+    /// assert_eq!(capped.get_odds(&[RollTarget::at_least_n_of(11, &symbols)]), 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clamp(&self, symbols: &[DieSymbol], max: usize) -> RollProbabilities {
+        self.relabel(|poss| {
+            let mut excess = poss.count_of(symbols).saturating_sub(max);
+            let mut clamped = poss.clone();
+            for symbol in symbols.iter().rev() {
+                let removed = excess.min(clamped.symbols.get_count(symbol));
+                clamped.symbols.sub_amount(symbol, removed);
+                excess -= removed;
+            }
+            clamped
+        })
+    }
+
+    /// Creates a new [`RollProbabilities`](crate::rolls::RollProbabilities) where every result with fewer than `min` of the provided 
+    /// [`DieSymbols`](crate::dice::DieSymbol) is relabeled to have exactly `min` of them, so the lower results merge into the minimum. 
+    /// The missing symbols are added as the first provided symbol, and other symbols are left unchanged. No results are dropped.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let two_d6s = RollProbabilities::new(&[standard::d6(), standard::d6()], &policy)?;
+    /// 
+    /// let floored = two_d6s.floor(&symbols, 4);
+    /// 
+    /// assert_eq!(floored.get_odds(&[RollTarget::exactly_n_of(4, &symbols)]), 6.0 / 36.0);
+    /// assert_eq!(floored.get_odds(&[RollTarget::at_most_n_of(3, &symbols)]), 0.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn floor(&self, symbols: &[DieSymbol], min: usize) -> RollProbabilities {
+        self.relabel(|poss| {
+            let missing = min.saturating_sub(poss.count_of(symbols));
+            match symbols.first() {
+                Some(symbol) => poss.add_symbols(&vec![ symbol.clone(); missing ]),
+                None => poss.clone()
+            }
+        })
+    }
+
+    fn keep_one_of_two(&self, symbols: &[DieSymbol], keep: Ordering) -> Result<RollProbabilities, String> {
+        let total = self.total.checked_mul(self.total).ok_or_else(Self::combinations_overflow)?;
+        let mut occur = HashMap::new();
+        for ((first, first_occur), (second, second_occur)) in 
+                self.occurrences.iter()
+                .cartesian_product(self.occurrences.iter()) {
+            let kept = 
+                if second.count_of(symbols).cmp(&first.count_of(symbols)) == keep { second } 
+                else { first };
+            // bounded by the total checked above
+            *occur.entry(kept.clone()).or_insert(0) += first_occur * second_occur;
+        }
+        Ok(RollProbabilities {
+            occurrences: occur,
+            total
+        })
+    }
+
+    /// Creates a new [`RollProbabilities`](crate::rolls::RollProbabilities) representing the roll being made twice, 
+    /// keeping the result with the most of the provided [`DieSymbols`](crate::dice::DieSymbol). 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d20 = RollProbabilities::new(&[standard::d20()], &policy)?;
+    /// 
+    /// let advantage = d20.with_advantage(&symbols)?;
+    /// 
+    /// assert_eq!(advantage.get_odds(&[RollTarget::at_least_n_of(11, &symbols)]), 0.75);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_advantage(&self, symbols: &[DieSymbol]) -> Result<RollProbabilities, String> {
+        self.keep_one_of_two(symbols, Ordering::Greater)
+    }
+
+    /// Creates a new [`RollProbabilities`](crate::rolls::RollProbabilities) representing the roll being made twice, 
+    /// keeping the result with the fewest of the provided [`DieSymbols`](crate::dice::DieSymbol). 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d20 = RollProbabilities::new(&[standard::d20()], &policy)?;
+    /// 
+    /// let disadvantage = d20.with_disadvantage(&symbols)?;
+    /// 
+    /// assert_eq!(disadvantage.get_odds(&[RollTarget::at_least_n_of(11, &symbols)]), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_disadvantage(&self, symbols: &[DieSymbol]) -> Result<RollProbabilities, String> {
+        self.keep_one_of_two(symbols, Ordering::Less)
+    }
+
+    /// Returns the joint probability of every pairing of this roll's totals with the other roll's totals for the provided [`DieSymbols`](crate::dice::DieSymbol). 
+    /// Rows follow this roll's [`achievable_totals`](crate::rolls::RollProbabilities::achievable_totals) and columns follow the other roll's, 
+    /// both in ascending order, so entry `[i][j]` is the probability of this roll having its `i`th smallest total while the other has its `j`th smallest.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let two_d4s = RollProbabilities::new(&[standard::d4(), standard::d4()], &policy)?;
+    /// 
+    /// let matrix = d4.payoff_matrix(&two_d4s, &symbols);
+    /// 
+    /// assert_eq!(matrix.len(), 4);
+    /// assert_eq!(matrix[0].len(), 7);
+    /// assert_eq!(matrix[3][3], 0.25 * 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payoff_matrix(&self, other: &Self, symbols: &[DieSymbol]) -> Vec<Vec<f64>> {
+        let columns = other.distribution(symbols);
+        self.distribution(symbols).iter()
+            .map(|(_, row_p)| 
+                columns.iter()
+                .map(|(_, col_p)| row_p * col_p)
+                .collect())
+            .collect()
+    }
+
+    fn margin_occurrences(&self, other: &Self, symbols: &[DieSymbol]) -> Result<(BTreeMap<i64, usize>, usize), String> {
+        let this_counts = self.signed_count_occurrences(symbols);
+        let other_counts = other.signed_count_occurrences(symbols);
+        let mut margins = BTreeMap::new();
+        let mut total: usize = 0;
+        for ((this_total, this_occ), (other_total, other_occ)) in this_counts.iter().cartesian_product(other_counts.iter()) {
+            let occurrences = this_occ.checked_mul(*other_occ).ok_or_else(Self::combinations_overflow)?;
+            total = total.checked_add(occurrences).ok_or_else(Self::combinations_overflow)?;
+            // bounded by the total checked above
+            *margins.entry(this_total - other_total).or_insert(0) += occurrences;
+        }
+        Ok((margins, total))
+    }
+
+    /// Returns the probability of each signed margin between this roll's total and the other roll's total for the provided [`DieSymbols`](crate::dice::DieSymbol), 
+    /// as `(this total - other total, probability)` pairs sorted by margin, or an empty `Vec` if either roll is empty. 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d4 = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// 
+    /// let margins = d4.margin_distribution(&d4, &symbols)?;
+    /// 
+    /// assert_eq!(margins.len(), 7);
+    /// assert_eq!(margins[0], (-3, 0.0625));
+    /// assert_eq!(margins[3], (0, 0.25));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn margin_distribution(&self, other: &Self, symbols: &[DieSymbol]) -> Result<Vec<(i64, f64)>, String> {
+        let (margins, total) = self.margin_occurrences(other, symbols)?;
+        Ok(margins.into_iter()
+            .map(|(margin, occ)| (margin, occ as f64 / total as f64))
+            .collect())
+    }
+
+    /// Returns the variance of the signed margin between this roll's total and the other roll's total for the provided [`DieSymbols`](crate::dice::DieSymbol). 
+    /// A high variance means the matchup swings widely from roll to roll, a low variance means it is predictable, and it is `0.0` if either roll is empty. 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d6 = RollProbabilities::new(&[standard::d6()], &policy)?;
+    /// 
+    /// let variance = d6.margin_variance(&d6, &symbols)?;
+    /// 
+    /// assert!((variance - 35.0 / 6.0).abs() < 1e-12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn margin_variance(&self, other: &Self, symbols: &[DieSymbol]) -> Result<f64, String> {
+        let (margins, total) = self.margin_occurrences(other, symbols)?;
+        if total == 0 {
+            return Ok(0.0);
+        }
+        let total = total as f64;
+        let mean = margins.iter()
+            .map(|(margin, occ)| *margin as f64 * *occ as f64)
+            .sum::<f64>() / total;
+        Ok(margins.iter()
+            .map(|(margin, occ)| (*margin as f64 - mean).powi(2) * *occ as f64)
+            .sum::<f64>() / total)
+    }
+
+    fn compare_by<F: Fn(&RollOutcome) -> i64>(&self, other: &Self, value_of: F) -> Result<RollCompareResult, String> {
+        let (wins, ties, losses) = 
+            self.occurrences.iter()
+            .cartesian_product(other.occurrences.iter())
+            .try_fold((0usize, 0usize, 0usize), |(wins, ties, losses), (this_poss, other_poss)| {
+                let occurrences = this_poss.1.checked_mul(*other_poss.1)?;
+                Some(match value_of(this_poss.0).cmp(&value_of(other_poss.0)) {
+                    Ordering::Greater => (wins.checked_add(occurrences)?, ties, losses),
+                    Ordering::Equal => (wins, ties.checked_add(occurrences)?, losses),
+                    Ordering::Less => (wins, ties, losses.checked_add(occurrences)?)
+                })
+            })
+            .ok_or_else(Self::combinations_overflow)?;
+        wins.checked_add(ties).and_then(|n| n.checked_add(losses)).ok_or_else(Self::combinations_overflow)?;
+        Ok(RollCompareResult::new(wins, ties, losses))
+    }
+
+    /// Compares the results of one roll against another, returning a new [`RollCompareResult`](crate::rolls::RollCompareResult). 
+    /// Each roll's value is the total of every [`DieSymbol`](crate::dice::DieSymbol) it collected, so both rolls should have been collected 
+    /// with the same symbols. Use [`roll_against_symbols`](crate::rolls::RollProbabilities::roll_against_symbols) when the pools carry different symbols. 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::rolls::RollCompareResult;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![standard::pip()];
+    /// let d8_pool = vec![standard::d8()];
+    /// let d4_pool = vec![standard::d4()];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d8_result = RollProbabilities::new(&d8_pool, &policy)?;
+    /// let d4_result = RollProbabilities::new(&d4_pool, &policy)?;
+    /// 
+    /// let compare = d8_result.roll_against(&d4_result)?;
+    /// 
+    /// assert_eq!(compare.win_odds(), 0.6875);
+    /// assert_eq!(compare.tie_odds(), 0.125);
+    /// assert_eq!(compare.loss_odds(), 0.1875);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roll_against(&self, other: &Self) -> Result<RollCompareResult, String> {
+        self.compare_by(other, |poss| poss.signed_total_count())
+    }
+
+    /// Compares the results of one roll against another using only the provided [`DieSymbols`](crate::dice::DieSymbol), 
+    /// returning a new [`RollCompareResult`](crate::rolls::RollCompareResult). Any other symbols collected by either roll are ignored. 
+    /// Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let skull = DieSymbol::new("Skull")?;
+    /// let cursed_d4 = Die::new(vec![
+    ///     DieSide::new(vec![ standard::pip(), skull.clone() ]),
+    ///     DieSide::new(vec![ standard::pip(), standard::pip(), skull.clone() ]),
+    ///     DieSide::new(vec![ standard::pip(), standard::pip(), standard::pip(), skull.clone() ]),
+    ///     DieSide::new(vec![ standard::pip(), standard::pip(), standard::pip(), standard::pip(), skull.clone() ]) ])?;
+    /// let all_symbols = vec![ standard::pip(), skull ];
+    /// let pips = vec![ standard::pip() ];
+    /// let policy = RollCollectionPolicy::collect_all(&all_symbols);
+    /// let cursed_result = RollProbabilities::new(&[cursed_d4], &policy)?;
+    /// let d4_result = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// 
+    /// let compare = cursed_result.roll_against_symbols(&d4_result, &pips)?;
+    /// 
+    /// assert_eq!(compare.win_odds(), compare.loss_odds());
+    /// assert_eq!(compare.tie_odds(), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roll_against_symbols(&self, other: &Self, symbols: &[DieSymbol]) -> Result<RollCompareResult, String> {
+        self.compare_by(other, |poss| poss.signed_count_of(symbols))
+    }
+
+    /// Compares `rounds` independent rolls of this roll against the other, as in [`roll_against`](crate::rolls::RollProbabilities::roll_against), 
+    /// returning a new [`RollCompareResult`](crate::rolls::RollCompareResult) for the whole contest. A tied round counts as neither a win nor a loss, 
+    /// so the contest is won when more rounds are won than lost, tied when they are equal, and lost otherwise. 
+    /// Zero rounds is always a tie. Returns `Err` if there are too many combinations to count in a `usize`, else returns `Ok`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// let symbols = vec![standard::pip()];
+    /// let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// let d8_result = RollProbabilities::new(&[standard::d8()], &policy)?;
+    /// let d4_result = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// 
+    /// let compare = d8_result.best_of(&d4_result, 3)?;
+    /// 
+    /// assert_eq!(compare.win_odds(), 3278.0 / 4096.0);
+    /// assert_eq!(compare.tie_odds(), 404.0 / 4096.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn best_of(&self, other: &Self, rounds: usize) -> Result<RollCompareResult, String> {
+        fn gcd(a: usize, b: usize) -> usize {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+        let single = self.roll_against(other)?;
+        let divisor = gcd(gcd(single.wins, single.ties), single.losses);
+        if divisor == 0 {
+            return Ok(RollCompareResult::new(0, 0, 0));
+        }
+        let (win, tie, loss) = (single.wins / divisor, single.ties / divisor, single.losses / divisor);
+        if rounds == 0 {
+            return Ok(RollCompareResult::new(0, 1, 0));
+        }
+        if win + tie + loss == 1 {
+            // only one result is possible, so every round goes the same way
+            return Ok(RollCompareResult::new(win, tie, loss));
+        }
+        // every term of the expansion is part of this total, so none of them can overflow once it fits
+        u32::try_from(rounds).ok()
+            .and_then(|rounds| (win + tie + loss).checked_pow(rounds))
+            .ok_or_else(Self::combinations_overflow)?;
+        let (mut wins, mut ties, mut losses) = (0, 0, 0);
+        for won in 0..=rounds {
+            for lost in 0..=(rounds - won) {
+                let tied = rounds - won - lost;
+                let count = Self::multinomial(&[won, lost, tied]) * win.pow(won as u32) * loss.pow(lost as u32) * tie.pow(tied as u32);
+                let tally = match won.cmp(&lost) {
+                    Ordering::Greater => &mut wins,
+                    Ordering::Equal => &mut ties,
+                    Ordering::Less => &mut losses
+                };
+                *tally += count;
+            }
+        }
+        Ok(RollCompareResult::new(wins, ties, losses))
+    }
+}
+/// Tallies how many [`Dice`](crate::dice::Die) in the pool carry each [`DieSymbol`](crate::dice::DieSymbol) on at least one side. 
+/// Useful for checking that the symbols used in a [`RollTarget`](crate::rolls::RollTarget) actually appear in the pool
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::{DieSymbol, DieSide, Die};
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::symbol_report;
+/// # fn main() -> Result<(), String> {
+/// let hit = DieSymbol::new("Hit")?;
+/// let custom = Die::new(vec![ DieSide::new(vec![ hit.clone() ]), DieSide::new(vec![]) ])?;
+/// let dice = vec![ standard::d6(), standard::d6(), custom ];
+/// 
+/// let report = symbol_report(&dice);
+/// 
+/// assert_eq!(report[&standard::pip()], 2);
+/// assert_eq!(report[&hit], 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn symbol_report(dice: &[Die]) -> HashMap<DieSymbol, usize> {
+    let mut report = HashMap::new();
+    for symbol in dice.iter().flat_map(|d| d.unique_symbols()) {
+        *report.entry(symbol).or_insert(0) += 1;
+    }
+    report
+}
+
+/// Rolls each [`Die`](crate::dice::Die) in the pool once, collects the result using the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy), 
+/// and returns `true` if all of the [`RollTargets`](crate::rolls::RollTarget) are met. 
+/// This is a single random trial, not a probability; use [`get_odds`](crate::rolls::RollProbabilities::get_odds) for the latter.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::{resolve, RollTarget, RollCollectionPolicy};
+/// # fn main() -> Result<(), String> {
+/// let symbols = vec![ standard::pip() ];
+/// let policy = RollCollectionPolicy::collect_all(&symbols);
+/// let dice = vec![ standard::d6(), standard::d6() ];
+/// let targets = vec![ RollTarget::at_least_n_of(2, &symbols) ];
+/// 
+/// let passed = resolve(&dice, &policy, &targets, &mut rand::thread_rng());
+/// 
+/// assert!(passed);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn resolve<R: Rng>(dice: &[Die], policy: &RollCollectionPolicy, targets: &[RollTarget], rng: &mut R) -> bool {
+    let pool = RolledPool::roll(dice, rng);
+    let roll: Vec<&DieSide> = pool.sides().iter().collect();
+    let collected = policy.apply(&roll);
+    let poss = RollOutcome::new().add_symbols(&collected);
+    targets.iter().all(|t| t.is_met_by(&poss))
+}
+
+/// For each position in the pool, returns the probability that the [`Die`](crate::dice::Die) at that position is the first, 
+/// reading left to right, to show at least `threshold` of the provided [`DieSymbols`](crate::dice::DieSymbol). 
+/// Positions are indexes into `dice`, and a final entry at position `dice.len()` holds the probability that no die succeeds.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::first_success_position;
+/// # fn main() -> Result<(), String> {
+/// let symbols = vec![ standard::pip() ];
+/// let dice = vec![ standard::d4(), standard::d4() ];
+/// 
+/// let positions = first_success_position(&dice, &symbols, 4);
+/// 
+/// assert_eq!(positions, vec![ (0, 0.25), (1, 0.1875), (2, 0.5625) ]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn first_success_position(dice: &[Die], symbols: &[DieSymbol], threshold: usize) -> Vec<(usize, f64)> {
+    let mut positions = Vec::with_capacity(dice.len() + 1);
+    let mut none_yet = 1.0;
+    for (i, die) in dice.iter().enumerate() {
+        let successes: usize = 
+            die.weighted_sides()
+            .filter(|(side, _)| side.symbols().iter().filter(|s| symbols.contains(s)).count() >= threshold)
+            .map(|(_, weight)| weight)
+            .sum();
+        let success_odds = (successes as f64) / (die.total_weight() as f64);
+        positions.push((i, none_yet * success_odds));
+        none_yet *= 1.0 - success_odds;
+    }
+    positions.push((dice.len(), none_yet));
+    positions
+}
+
+/// Returns the probability that every [`Die`](crate::dice::Die) in the pool shows a different number of the [`DieSymbol`](crate::dice::DieSymbol), 
+/// such as rolling no pairs. Returns `1.0` if provided slice contains no elements.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::all_distinct_probability;
+/// # fn main() -> Result<(), String> {
+/// let dice = vec![ standard::d6(), standard::d6() ];
+/// 
+/// let no_pair = all_distinct_probability(&dice, &standard::pip());
+/// 
+/// assert_eq!(no_pair, 30.0 / 36.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn all_distinct_probability(dice: &[Die], symbol: &DieSymbol) -> f64 {
+    if dice.is_empty() {
+        return 1.0;
+    }
+    let mut distinct = 0;
+    let mut total = 0;
+    for roll in dice.iter()
+            .map(|x| x.weighted_sides())
+            .multi_cartesian_product() {
+        let weight: usize = roll.iter().map(|(_, w)| w).product();
+        total += weight;
+        if roll.iter()
+                .map(|(side, _)| side.count_of(symbol))
+                .all_unique() {
+            distinct += weight;
+        }
+    }
+    (distinct as f64) / (total as f64)
+}
+
+/// Returns the probability of each achievable difference between the number of the [`DieSymbol`](crate::dice::DieSymbol) shown on die `a` 
+/// and die `b` when rolled together, as `(a - b, probability)` pairs sorted by difference.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::difference_distribution;
+/// # fn main() -> Result<(), String> {
+/// let differences = difference_distribution(&standard::d4(), &standard::d4(), &standard::pip());
+/// 
+/// assert_eq!(differences.len(), 7);
+/// assert_eq!(differences[0], (-3, 0.0625));
+/// assert_eq!(differences[3], (0, 0.25));
+/// # Ok(())
+/// # }
+/// ```
+pub fn difference_distribution(a: &Die, b: &Die, symbol: &DieSymbol) -> Vec<(i64, f64)> {
+    let count_on = |side: &DieSide| side.count_of(symbol) as i64;
+    let mut differences: BTreeMap<i64, usize> = BTreeMap::new();
+    for ((a_side, a_weight), (b_side, b_weight)) in a.weighted_sides().cartesian_product(b.weighted_sides()) {
+        *differences.entry(count_on(a_side) - count_on(b_side)).or_insert(0) += a_weight * b_weight;
+    }
+    let total = (a.total_weight() * b.total_weight()) as f64;
+    differences.into_iter()
+        .map(|(diff, occurrences)| (diff, (occurrences as f64) / total))
+        .collect()
+}
+
+/// Represents [`RollProbabilities`](crate::rolls::RollProbabilities) which were either computed exactly or estimated from random samples
+#[cfg(feature = "rand")]
+pub enum RollEstimate {
+    /// Every combination of sides was enumerated
+    Exact(RollProbabilities),
+    /// The probabilities were estimated by randomly rolling the pool
+    Approximate(RollProbabilities)
+}
+
+#[cfg(feature = "rand")]
+impl RollEstimate {
+    /// Returns `true` if the probabilities were computed exactly
+    pub fn is_exact(&self) -> bool {
+        matches!(self, RollEstimate::Exact(_))
+    }
+
+    /// Returns the underlying [`RollProbabilities`](crate::rolls::RollProbabilities), whether exact or approximate
+    pub fn probabilities(&self) -> &RollProbabilities {
+        match self {
+            RollEstimate::Exact(probabilities) => probabilities,
+            RollEstimate::Approximate(probabilities) => probabilities
+        }
+    }
+}
+
+/// Returns the probability that every pool meets all of its own [`RollTargets`](crate::rolls::RollTarget), treating the pools as independent rolls. 
+/// Returns `1.0` if provided slice contains no elements.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::{all_pass, RollTarget, RollProbabilities, RollCollectionPolicy};
+/// # fn main() -> Result<(), String> {
+/// let symbols = vec![ standard::pip() ];
+/// let policy = RollCollectionPolicy::collect_all(&symbols);
+/// let fighter = RollProbabilities::new(&[standard::d20()], &policy)?;
+/// let wizard = RollProbabilities::new(&[standard::d4()], &policy)?;
+/// 
+/// let party = all_pass(&[
+///     (&fighter, vec![ RollTarget::at_least_n_of(11, &symbols) ]),
+///     (&wizard, vec![ RollTarget::at_least_n_of(3, &symbols) ]) ]);
+/// 
+/// assert_eq!(party, 0.25);
+/// # Ok(())
+/// # }
+/// ```
+pub fn all_pass(pools: &[(&RollProbabilities, Vec<RollTarget>)]) -> f64 {
+    pools.iter()
+        .map(|(pool, targets)| pool.get_odds(targets))
+        .product()
+}
+
+/// Returns the probability that at least one pool meets all of its own [`RollTargets`](crate::rolls::RollTarget), treating the pools as independent rolls. 
+/// Returns `0.0` if provided slice contains no elements.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::{any_pass, RollTarget, RollProbabilities, RollCollectionPolicy};
+/// # fn main() -> Result<(), String> {
+/// let symbols = vec![ standard::pip() ];
+/// let policy = RollCollectionPolicy::collect_all(&symbols);
+/// let fighter = RollProbabilities::new(&[standard::d20()], &policy)?;
+/// let wizard = RollProbabilities::new(&[standard::d4()], &policy)?;
+/// 
+/// let anyone = any_pass(&[
+///     (&fighter, vec![ RollTarget::at_least_n_of(11, &symbols) ]),
+///     (&wizard, vec![ RollTarget::at_least_n_of(3, &symbols) ]) ]);
+/// 
+/// assert_eq!(anyone, 0.75);
+/// # Ok(())
+/// # }
+/// ```
+pub fn any_pass(pools: &[(&RollProbabilities, Vec<RollTarget>)]) -> f64 {
+    if pools.is_empty() {
+        return 0.0;
+    }
+    let none_pass: f64 = 
+        pools.iter()
+        .map(|(pool, targets)| 1.0 - pool.get_odds(targets))
+        .product();
+    1.0 - none_pass
+}
+
+const MAX_BUDGET_POOLS: usize = 10_000;
+
+/// Returns every non-empty pool of [`Dice`](crate::dice::Die) that can be bought from the menu without the summed cost exceeding the budget. 
+/// Each menu entry is a die and its cost, and an entry may be picked any number of times. Each pool is a multiset, so it is returned once 
+/// with its dice in menu order. Pools are listed depth-first by menu position: a pool is followed by every pool that extends it, 
+/// so `[a]` comes before `[a, a]`, which comes before `[a, b]` and `[b]`. 
+/// Returns `Err` if any menu entry costs `0` or more than 10,000 pools fit within the budget, else returns `Ok`.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::pools_within_budget;
+/// # fn main() -> Result<(), String> {
+/// let menu = vec![ (standard::d4(), 1), (standard::d6(), 2) ];
+/// 
+/// let pools = pools_within_budget(&menu, 2)?;
+/// 
+/// // [d4], [d4, d4], [d6]
+/// assert_eq!(pools.len(), 3);
+/// assert_eq!(pools[1].len(), 2);
+/// assert_eq!(pools[2][0].sides().len(), 6);
+/// # Ok(())
+/// # }
+/// ```
+pub fn pools_within_budget(menu: &[(Die, usize)], budget: usize) -> Result<Vec<Vec<Die>>, String> {
+    if menu.iter().any(|(_, cost)| *cost == 0) {
+        return Err("every menu entry must have a nonzero cost".to_string());
+    }
+    let mut pools = Vec::new();
+    let mut pending: Vec<(usize, usize, Vec<usize>)> = vec![ (0, budget, vec![]) ];
+    while let Some((start, remaining, picks)) = pending.pop() {
+        if !picks.is_empty() {
+            if pools.len() == MAX_BUDGET_POOLS {
+                return Err(format!("more than {} pools fit within the budget", MAX_BUDGET_POOLS));
+            }
+            pools.push(picks.iter().map(|i| menu[*i].0.clone()).collect());
         }
-    }
-    
-    /// Policy for removing the lowest N dice and collecting the rest, ordering by number of matching symbols
-    pub fn remove_lowest_n_of(n:usize, symbols: &'a [DieSymbol]) -> RollCollectionPolicy {
-        RollCollectionPolicy {
-            coll_type: RollCollectionTypes::RemoveLowestN(n),
-            symbols
+        for index in (start..menu.len()).rev() {
+            let cost = menu[index].1;
+            if cost <= remaining {
+                let mut next = picks.clone();
+                next.push(index);
+                pending.push((index, remaining - cost, next));
+            }
         }
     }
+    Ok(pools)
 }
 
-/// Tracks the probabilities of a roll of one or more dice
-pub struct RollProbabilities {
-    occurrences: HashMap<RollResultPossibility, usize>,
-    total: usize
+/// Returns how much adding the `extra` [`Die`](crate::dice::Die) to the pool changes the probability of meeting all of the [`RollTargets`](crate::rolls::RollTarget), 
+/// as the odds with the extra die minus the odds without it. Both pools are enumerated in full, so the cost is that of the larger pool. 
+/// An empty base pool has no symbols, so it only meets targets that are satisfied by a count of `0`. 
+/// Returns `Err` if either pool could not be enumerated, else returns `Ok`.
+/// 
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use art_dice::dice::standard;
+/// # use art_dice::rolls::{marginal_gain, RollTarget, RollCollectionPolicy};
+/// # fn main() -> Result<(), String> {
+/// let symbols = vec![ standard::pip() ];
+/// let policy = RollCollectionPolicy::collect_all(&symbols);
+/// let targets = vec![ RollTarget::at_least_n_of(5, &symbols) ];
+/// 
+/// let gain = marginal_gain(&[standard::d4()], &standard::d4(), &policy, &targets)?;
+/// 
+/// assert_eq!(gain, 0.625);
+/// # Ok(())
+/// # }
+/// ```
+pub fn marginal_gain(dice: &[Die], extra: &Die, policy: &RollCollectionPolicy, targets: &[RollTarget]) -> Result<f64, String> {
+    let base_odds = 
+        if dice.is_empty() {
+            PoolDistribution::new().odds(targets)
+        } else {
+            RollProbabilities::new(dice, policy)?.get_odds(targets)
+        };
+    let mut extended = dice.to_vec();
+    extended.push(extra.clone());
+    let extended_odds = RollProbabilities::new(&extended, policy)?.get_odds(targets);
+    Ok(extended_odds - base_odds)
 }
 
-impl RollProbabilities {
-    fn collect_symbols(roll: &[&DieSide], policy: &RollCollectionPolicy) -> Vec<DieSymbol> {
-        let mut filtered_sides: Vec<Vec<DieSymbol>> =
-            roll.iter()
-            .map(|x| 
-                x.symbols().iter()
-                .filter(|y| policy.symbols.contains(y))
-                .cloned().collect())
-            .collect();
-        filtered_sides.sort_by(|x,y| x.len().cmp(&y.len()));
-        filtered_sides.reverse();
-        let sides_len = filtered_sides.len();
-        match policy.coll_type {
-            RollCollectionTypes::CollectAll => 
-                filtered_sides.iter()
-                .flatten().cloned().collect(),
-            RollCollectionTypes::TakeHighestN(n) => 
-                filtered_sides.iter().take(n)
-                .flatten().cloned().collect(),
-            RollCollectionTypes::TakeLowestN(n) => 
-                filtered_sides.iter().skip(sides_len - n)
-                .flatten().cloned().collect(),
-            RollCollectionTypes::RemoveHighestN(n) =>
-                filtered_sides.iter().skip(n)
-                .flatten().cloned().collect(),
-            RollCollectionTypes::RemoveLowestN(n) =>
-                filtered_sides.iter().take(sides_len - n)
-                .flatten().cloned().collect()
-        }
+/// Tracks the probabilities of a pool of dice as dice are added one at a time, collecting all matching symbols from every die. 
+/// Each added die is combined with the existing probabilities rather than enumerating the whole pool again
+pub struct PoolDistribution {
+    probabilities: RollProbabilities
+}
+
+impl Default for PoolDistribution {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// Creates a new instance of [`RollProbabilities`](crate::rolls::RollProbabilities) based on the provided collection of [`Dice`](crate::dice::Die). 
-    /// Die sides are collected based on the provided [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy). 
-    /// Returns `Err` if provided slice contains no elements, else returns `Ok`.
+impl PoolDistribution {
+    /// Creates a new, empty [`PoolDistribution`](crate::rolls::PoolDistribution), where every count is certain to be `0`
     /// 
     /// # Example
     /// ```rust
     /// # use std::error::Error;
-    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
     /// # use art_dice::dice::standard;
-    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # use art_dice::rolls::{PoolDistribution, RollTarget};
     /// # fn main() -> Result<(), String> {
-    /// let symbols = vec![ standard::pip() ] ;
-    /// let policy = RollCollectionPolicy::collect_all(&symbols);
-    /// let dice = vec![standard::d4(), standard::d4()];
+    /// let symbols = vec![ standard::pip() ];
     /// 
-    /// let two_d4s = RollProbabilities::new(&dice, &policy)?;
+    /// let pool = PoolDistribution::new();
+    /// 
+    /// assert_eq!(pool.odds(&[RollTarget::exactly_n_of(0, &symbols)]), 1.0);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn new(dice: &[Die], policy: &RollCollectionPolicy) -> Result<RollProbabilities, String> {
-        if dice.len() == 0 {
-            return Err("must include at least one die".to_string());
-        }
-        let mut occur = HashMap::new();
-        for roll in dice.into_iter()
-                .map(|x| x.sides())
-                .multi_cartesian_product() {
-            let collected = Self::collect_symbols(&roll, policy);
-            let new_poss = 
-                RollResultPossibility::new()
-                .add_symbols(&collected);
-            if occur.contains_key(&new_poss) {
-                occur.get_mut(&new_poss).map(|x| *x += 1);
-            } else {
-                occur.insert(new_poss, 1);
-            }
+    pub fn new() -> PoolDistribution {
+        let mut occurrences = HashMap::new();
+        occurrences.insert(RollOutcome::new(), 1);
+        PoolDistribution {
+            probabilities: RollProbabilities { occurrences, total: 1 }
         }
-        let total = occur.values().sum();
-        Ok(RollProbabilities {
-            occurrences: occur,
-            total: total
-        })
     }
 
-    /// Retrieves the probability of the roll achieving all of the [`RollTargets`](crate::rolls::RollTarget). 
-    /// Note that the roll's [`DieSymbols`](crate::dice::DieSymbol) will have been filtered down based
-    /// on the [`RollCollectionPolicy`](crate::rolls::RollCollectionPolicy) used to generate the probability
+    /// Adds a [`Die`](crate::dice::Die) to the pool, collecting the provided [`DieSymbols`](crate::dice::DieSymbol) from its sides. 
+    /// Returns `Err` and leaves the pool unchanged if there are too many combinations to count in a `usize`, else returns `Ok`
     /// 
-    /// # Examples
+    /// # Example
     /// ```rust
     /// # use std::error::Error;
-    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
     /// # use art_dice::dice::standard;
-    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # use art_dice::rolls::{PoolDistribution, RollTarget};
     /// # fn main() -> Result<(), String> {
-    /// let dice = vec![standard::d4(), standard::d4()];
     /// let symbols = vec![ standard::pip() ];
-    /// let policy = RollCollectionPolicy::collect_all(&symbols);
-    /// let two_d4s = RollProbabilities::new(&dice, &policy)?;
+    /// let mut pool = PoolDistribution::new();
     /// 
-    /// let exactly_3 = two_d4s.get_odds(&vec![ RollTarget::exactly_n_of(3, &symbols)]);
-    /// let at_least_6 = two_d4s.get_odds(&vec![ RollTarget::at_least_n_of(6, &symbols)]);
-    /// let at_most_5 = two_d4s.get_odds(&vec![ RollTarget::at_most_n_of(5, &symbols)]);
+    /// pool.add_die(&standard::d4(), &symbols)?;
+    /// pool.add_die(&standard::d4(), &symbols)?;
     /// 
-    /// assert_eq!(exactly_3, 0.125);
-    /// assert_eq!(at_least_6, 0.375);
-    /// assert_eq!(at_most_5, 0.625);
+    /// assert_eq!(pool.odds(&[RollTarget::exactly_n_of(5, &symbols)]), 0.25);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_odds(&self, targets: &[RollTarget]) -> f64 {
-        if self.total == 0 {
-            return 0.0;
-        }
-
-        let mut total_occurrences = 0;
-        for poss in self.occurrences.keys() {
-            let mut cond = true;
-            for target in targets {
-                let mut count: usize = 0;
-                for symbol in target.symbols {
-                    count += poss.symbols.get_count(&symbol);
-                }
-                cond = cond & match target.target_type {
-                    RollTargetTypes::Exactly => count == target.amount,
-                    RollTargetTypes::AtLeast => count >= target.amount,
-                    RollTargetTypes::AtMost => count <= target.amount
-                };
-            }
-            if cond {
-                total_occurrences += self.occurrences[poss];
+    pub fn add_die(&mut self, die: &Die, symbols: &[DieSymbol]) -> Result<(), String> {
+        let total = 
+            self.probabilities.total.checked_mul(die.total_weight())
+            .ok_or_else(RollProbabilities::combinations_overflow)?;
+        let mut occur = HashMap::new();
+        for (poss, occurrences) in self.probabilities.occurrences.iter() {
+            for (side, weight) in die.weighted_sides() {
+                let collected: Vec<DieSymbol> = 
+                    side.symbols().iter()
+                    .filter(|s| symbols.contains(s))
+                    .cloned()
+                    .collect();
+                // bounded by the total checked above
+                *occur.entry(poss.add_symbols(&collected)).or_insert(0) += occurrences * weight;
             }
         }
-        return (total_occurrences as f64) / (self.total as f64);
+        self.probabilities = RollProbabilities {
+            occurrences: occur,
+            total
+        };
+        Ok(())
     }
 
-    /// Compares the results of one roll against another, returning a new [`RollCompareResult`](crate::rolls::RollCompareResult)
+    /// Retrieves the probability of the pool achieving all of the [`RollTargets`](crate::rolls::RollTarget), 
+    /// in the same way as [`get_odds`](crate::rolls::RollProbabilities::get_odds)
     /// 
     /// # Example
     /// ```rust
     /// # use std::error::Error;
-    /// # use art_dice::rolls::RollCompareResult;
-    /// # use art_dice::dice::{DieSymbol, DieSide, Die};
     /// # use art_dice::dice::standard;
-    /// # use art_dice::rolls::{RollTarget, RollProbabilities, RollCollectionPolicy};
+    /// # use art_dice::rolls::{PoolDistribution, RollTarget};
     /// # fn main() -> Result<(), String> {
-    /// let symbols = vec![standard::pip()];
-    /// let d8_pool = vec![standard::d8()];
-    /// let d4_pool = vec![standard::d4()];
-    /// let policy = RollCollectionPolicy::collect_all(&symbols);
-    /// let d8_result = RollProbabilities::new(&d8_pool, &policy)?;
-    /// let d4_result = RollProbabilities::new(&d4_pool, &policy)?;
+    /// let symbols = vec![ standard::pip() ];
+    /// let mut pool = PoolDistribution::new();
+    /// pool.add_die(&standard::d8(), &symbols)?;
     /// 
-    /// let compare = d8_result.roll_against(&d4_result);
+    /// let at_least_7 = pool.odds(&[RollTarget::at_least_n_of(7, &symbols)]);
     /// 
-    /// assert_eq!(compare.win_odds(), 0.6875);
-    /// assert_eq!(compare.tie_odds(), 0.125);
-    /// assert_eq!(compare.loss_odds(), 0.1875);
+    /// assert_eq!(at_least_7, 0.25);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn roll_against(&self, other: &Self) -> RollCompareResult {
-        let (wins,ties,losses) = 
-            self.occurrences.iter()
-            .cartesian_product(other.occurrences.iter())
-            .map(|(this_poss, other_poss)| {
-                let this_val = this_poss.0.total_count();
-                let other_val = other_poss.0.total_count();
-                let occurrences = this_poss.1 * other_poss.1;
-                match this_val.cmp(&other_val) {
-                    Ordering::Greater => (occurrences, 0, 0),
-                    Ordering::Equal => (0, occurrences, 0),
-                    Ordering::Less => (0, 0, occurrences)
-                }})
-            .fold((0, 0, 0), |(x, y, z), (i, j ,k)| (x+i, y+j, z+k));
-        return RollCompareResult::new(wins, ties, losses);
+    pub fn odds(&self, targets: &[RollTarget]) -> f64 {
+        self.probabilities.get_odds(targets)
     }
 }
+
 /// Represents the probabilities of a roll against another pool of dice
 pub struct RollCompareResult {
     wins: usize,
@@ -355,7 +3143,7 @@ impl RollCompareResult {
     /// # let policy = RollCollectionPolicy::collect_all(&symbols);
     /// # let d8_result = RollProbabilities::new(&d8_pool, &policy)?;
     /// # let d4_result = RollProbabilities::new(&d4_pool, &policy)?;    
-    /// let compare = d8_result.roll_against(&d4_result);
+    /// let compare = d8_result.roll_against(&d4_result)?;
     /// 
     /// assert_eq!(compare.win_odds(), 0.6875);
     /// # Ok(())
@@ -385,7 +3173,7 @@ impl RollCompareResult {
     /// # let policy = RollCollectionPolicy::collect_all(&symbols);
     /// # let d8_result = RollProbabilities::new(&d8_pool, &policy)?;
     /// # let d4_result = RollProbabilities::new(&d4_pool, &policy)?;
-    /// let compare = d8_result.roll_against(&d4_result);
+    /// let compare = d8_result.roll_against(&d4_result)?;
     /// 
     /// assert_eq!(compare.tie_odds(), 0.125);
     /// # Ok(())
@@ -415,7 +3203,7 @@ impl RollCompareResult {
     /// # let policy = RollCollectionPolicy::collect_all(&symbols);
     /// # let d8_result = RollProbabilities::new(&d8_pool, &policy)?;
     /// # let d4_result = RollProbabilities::new(&d4_pool, &policy)?;
-    /// let compare = d8_result.roll_against(&d4_result);
+    /// let compare = d8_result.roll_against(&d4_result)?;
     /// 
     /// assert_eq!(compare.loss_odds(), 0.1875);
     /// # Ok(())
@@ -427,4 +3215,257 @@ impl RollCompareResult {
         }
         (self.losses as f64) / (self.total as f64)
     }
+
+    /// In a roll of [`a.roll_against(&b)`](crate::rolls::RollProbabilities::roll_against), returns the number of combinations in which roll `a`'s value exceeded roll `b`'s value
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// # let symbols = vec![standard::pip()];
+    /// # let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// # let d8_result = RollProbabilities::new(&[standard::d8()], &policy)?;
+    /// # let d4_result = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let compare = d8_result.roll_against(&d4_result)?;
+    /// 
+    /// assert_eq!(compare.wins(), 22);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wins(&self) -> usize {
+        self.wins
+    }
+
+    /// In a roll of [`a.roll_against(&b)`](crate::rolls::RollProbabilities::roll_against), returns the number of combinations in which roll `a`'s value matched roll `b`'s value
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// # let symbols = vec![standard::pip()];
+    /// # let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// # let d8_result = RollProbabilities::new(&[standard::d8()], &policy)?;
+    /// # let d4_result = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let compare = d8_result.roll_against(&d4_result)?;
+    /// 
+    /// assert_eq!(compare.ties(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ties(&self) -> usize {
+        self.ties
+    }
+
+    /// In a roll of [`a.roll_against(&b)`](crate::rolls::RollProbabilities::roll_against), returns the number of combinations in which roll `a`'s value fell short of roll `b`'s value
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// # let symbols = vec![standard::pip()];
+    /// # let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// # let d8_result = RollProbabilities::new(&[standard::d8()], &policy)?;
+    /// # let d4_result = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let compare = d8_result.roll_against(&d4_result)?;
+    /// 
+    /// assert_eq!(compare.losses(), 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn losses(&self) -> usize {
+        self.losses
+    }
+
+    /// In a roll of [`a.roll_against(&b)`](crate::rolls::RollProbabilities::roll_against), returns the number of combinations in which the rolls could be compared, which is the sum of the wins, ties and losses
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RollProbabilities, RollCollectionPolicy};
+    /// # fn main() -> Result<(), String> {
+    /// # let symbols = vec![standard::pip()];
+    /// # let policy = RollCollectionPolicy::collect_all(&symbols);
+    /// # let d8_result = RollProbabilities::new(&[standard::d8()], &policy)?;
+    /// # let d4_result = RollProbabilities::new(&[standard::d4()], &policy)?;
+    /// let compare = d8_result.roll_against(&d4_result)?;
+    /// 
+    /// assert_eq!(compare.total(), 32);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Represents a single concrete roll of one or more dice, holding the [`DieSide`](crate::dice::DieSide) each die landed on, 
+/// unlike a [`RollOutcome`](crate::rolls::RollOutcome) which only holds the symbols collected from a roll
+pub struct RolledPool {
+    sides: Vec<DieSide>
+}
+
+/// The name a [`RolledPool`](crate::rolls::RolledPool) was first given, kept so code written against it still compiles
+pub type Outcome = RolledPool;
+
+impl RolledPool {
+    /// Creates a new [`RolledPool`](crate::rolls::RolledPool) from the [`DieSides`](crate::dice::DieSide) that were rolled
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RolledPool;
+    /// # fn main() -> Result<(), String> {
+    /// let d6 = standard::d6();
+    /// let rolled = vec![ d6.sides()[2].clone(), d6.sides()[4].clone() ];
+    /// 
+    /// let pool = RolledPool::new(rolled);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(sides: Vec<DieSide>) -> RolledPool {
+        RolledPool { sides }
+    }
+
+    /// Rolls each [`Die`](crate::dice::Die) in the pool once and returns the resulting [`RolledPool`](crate::rolls::RolledPool)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RolledPool;
+    /// # fn main() -> Result<(), String> {
+    /// let dice = vec![ standard::d6(), standard::d6() ];
+    /// 
+    /// let pool = RolledPool::roll(&dice, &mut rand::thread_rng());
+    /// 
+    /// assert_eq!(pool.sides().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn roll<R: Rng>(dice: &[Die], rng: &mut R) -> RolledPool {
+        let sides = 
+            roll_pool(dice, rng).into_iter()
+            .cloned()
+            .collect();
+        RolledPool { sides }
+    }
+
+    /// Returns a slice of the rolled [`DieSides`](crate::dice::DieSide), in the same order as the dice that were rolled
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RolledPool;
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = standard::d6();
+    /// let pool = RolledPool::new(vec![ d6.sides()[2].clone(), d6.sides()[4].clone() ]);
+    /// 
+    /// assert_eq!(pool.sides()[1].symbols().len(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sides(&self) -> &[DieSide] {
+        self.sides.as_slice()
+    }
+
+    /// Returns the number of the provided [`DieSymbols`](crate::dice::DieSymbol) across all rolled sides
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RolledPool;
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = standard::d6();
+    /// let pool = RolledPool::new(vec![ d6.sides()[2].clone(), d6.sides()[4].clone() ]);
+    /// 
+    /// assert_eq!(pool.count_of(&[standard::pip()]), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_of(&self, symbols: &[DieSymbol]) -> usize {
+        self.sides.iter()
+            .flat_map(|s| s.symbols())
+            .filter(|s| symbols.contains(s))
+            .count()
+    }
+
+    /// Returns the number of all [`DieSymbols`](crate::dice::DieSymbol) across all rolled sides
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RolledPool;
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = standard::d6();
+    /// let pool = RolledPool::new(vec![ d6.sides()[2].clone(), d6.sides()[4].clone() ]);
+    /// 
+    /// assert_eq!(pool.total(), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total(&self) -> usize {
+        self.sides.iter()
+            .map(|s| s.symbols().len())
+            .sum()
+    }
+
+    /// Returns `true` if the rolled sides meet the [`RollTarget`](crate::rolls::RollTarget)
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::{RolledPool, RollTarget};
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = standard::d6();
+    /// let symbols = vec![ standard::pip() ];
+    /// let pool = RolledPool::new(vec![ d6.sides()[2].clone(), d6.sides()[4].clone() ]);
+    /// 
+    /// assert!(pool.meets(&RollTarget::at_least_n_of(8, &symbols)));
+    /// assert!(!pool.meets(&RollTarget::exactly_n_of(7, &symbols)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn meets(&self, target: &RollTarget) -> bool {
+        let poss = RollOutcome::from_symbols(self.symbols());
+        target.is_met_by(&poss)
+    }
+
+    /// Returns the count of each [`DieSymbol`](crate::dice::DieSymbol) across all rolled sides
+    /// 
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use art_dice::dice::standard;
+    /// # use art_dice::rolls::RolledPool;
+    /// # fn main() -> Result<(), String> {
+    /// # let d6 = standard::d6();
+    /// let pool = RolledPool::new(vec![ d6.sides()[2].clone(), d6.sides()[4].clone() ]);
+    /// 
+    /// let symbols = pool.symbols();
+    /// 
+    /// assert_eq!(symbols.get_count(&standard::pip()), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symbols(&self) -> ItemCounter<DieSymbol> {
+        let mut counter = ItemCounter::new();
+        for symbol in self.sides.iter().flat_map(|s| s.symbols()) {
+            counter.add(symbol);
+        }
+        counter
+    }
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::rolls::*;
 
 fn test_results_exactly(results: &RollProbabilities, symbols: &[DieSymbol], count: usize, expected: f64) {
     let target = RollTarget::exactly_n_of(count, symbols);
-    let odds = results.get_odds(&vec![ target ]);
+    let odds = results.get_odds(&[target]);
     assert_eq!(odds, expected);
 }
 
@@ -11,7 +11,7 @@ fn test_results_exactly(results: &RollProbabilities, symbols: &[DieSymbol], coun
 fn one_d4() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results = RollProbabilities::new(&vec![d4()], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4()], &policy).unwrap();
     assert_eq!(results.total, 4);
     
     
@@ -25,7 +25,7 @@ fn one_d4() {
 fn two_d4s() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d4()], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
     assert_eq!(results.total, 16);
     
     test_results_exactly(&results, &symbols, 1, 0.0);
@@ -42,7 +42,7 @@ fn two_d4s() {
 fn d4_and_d8() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d8() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d8()], &policy).unwrap();
     assert_eq!(results.total, 32);
     
     test_results_exactly(&results, &symbols, 1, 0.0);
@@ -63,7 +63,7 @@ fn d4_and_d8() {
 fn three_d4s() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d4(), d4() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d4(), d4()], &policy).unwrap();
         
     assert_eq!(results.total, 4*4*4);
     test_results_exactly(&results, &symbols, 7, 0.1875);
@@ -73,7 +73,7 @@ fn three_d4s() {
 fn four_through_ten() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d6(), d8(), d10() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d6(), d8(), d10()], &policy).unwrap();
 
     assert_eq!(results.total, 4*6*8*10);
 }
@@ -84,7 +84,7 @@ fn four_through_ten() {
 fn three_d4s_highest_two() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::take_highest_n_of(2, &symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d4(), d4() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d4(), d4()], &policy).unwrap();
 
     assert_eq!(results.total, 4*4*4);
     test_results_exactly(&results, &symbols, 2, 0.015625);
@@ -102,7 +102,7 @@ fn three_d4s_highest_two() {
 fn three_d4s_lowest_two() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::take_lowest_n_of(2, &symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d4(), d4() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d4(), d4()], &policy).unwrap();
 
     assert_eq!(results.total, 4*4*4);
     test_results_exactly(&results, &symbols, 2, 0.15625);
@@ -120,7 +120,7 @@ fn three_d4s_lowest_two() {
 fn three_d4s_remove_highest_two() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::remove_highest_n_of(2, &symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d4(), d4() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d4(), d4()], &policy).unwrap();
 
     assert_eq!(results.total, 4*4*4);
     test_results_exactly(&results, &symbols, 1, 0.578125);
@@ -135,7 +135,7 @@ fn three_d4s_remove_highest_two() {
 fn three_d4s_remove_lowest_two() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::remove_lowest_n_of(2, &symbols);
-    let results = RollProbabilities::new(&vec![ d4(), d4(), d4() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[d4(), d4(), d4()], &policy).unwrap();
 
     assert_eq!(results.total, 4*4*4);
     test_results_exactly(&results, &symbols, 1, 0.015625);
@@ -149,10 +149,10 @@ fn three_d4s_remove_lowest_two() {
 fn one_d4_compare_two_d4() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results1 = RollProbabilities::new(&vec![ d4()], &policy).unwrap();
-    let results2 = RollProbabilities::new(&vec![ d4(), d4()], &policy).unwrap();
+    let results1 = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let results2 = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
 
-    let compare = results1.roll_against(&results2);
+    let compare = results1.roll_against(&results2).unwrap();
 
     assert_eq!(compare.win_odds(), 4.0/64.0);
     assert_eq!(compare.tie_odds(), 6.0/64.0);
@@ -163,10 +163,10 @@ fn one_d4_compare_two_d4() {
 fn two_d4_compare_two_d4() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results1 = RollProbabilities::new(&vec![ d4(), d4()], &policy).unwrap();
-    let results2 = RollProbabilities::new(&vec![ d4(), d4()], &policy).unwrap();
+    let results1 = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+    let results2 = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
 
-    let compare = results1.roll_against(&results2);
+    let compare = results1.roll_against(&results2).unwrap();
 
     assert_eq!(compare.win_odds(), 106.0/256.0);
     assert_eq!(compare.tie_odds(), 44.0/256.0);
@@ -177,10 +177,10 @@ fn two_d4_compare_two_d4() {
 fn one_d8_compare_two_d4() {
     let symbols = d4().unique_symbols();
     let policy = RollCollectionPolicy::collect_all(&symbols);
-    let results1 = RollProbabilities::new(&vec![ d8()], &policy).unwrap();
-    let results2 = RollProbabilities::new(&vec![ d4(), d4()], &policy).unwrap();
+    let results1 = RollProbabilities::new(&[d8()], &policy).unwrap();
+    let results2 = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
 
-    let compare = results1.roll_against(&results2);
+    let compare = results1.roll_against(&results2).unwrap();
 
     assert_eq!(compare.win_odds(), 48.0/128.0);
     assert_eq!(compare.tie_odds(), 16.0/128.0);
@@ -200,7 +200,7 @@ fn two_custom_d4_multiple_targets() {
     ];
     let custom_d4 = Die::new(sides).unwrap();
     let policy = RollCollectionPolicy::collect_all(&both_symbols);
-    let results = RollProbabilities::new(&vec![ custom_d4.clone(), custom_d4.clone() ], &policy).unwrap();
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4.clone()], &policy).unwrap();
 
     let a_symbol_vec = vec![ a_symbol.clone() ];
     let b_symbol_vec = vec![ b_symbol.clone() ];
@@ -209,10 +209,1910 @@ fn two_custom_d4_multiple_targets() {
     let target_at_least_one_b = RollTarget::at_least_n_of(1, &b_symbol_vec);
 
     assert_eq!(results.total, 4*4);
-    let results_exactly_one_a = results.get_odds(&vec![target_exactly_one_a.clone()]);
+    let results_exactly_one_a = results.get_odds(&[target_exactly_one_a]);
     assert_eq!(results_exactly_one_a, 8.0/16.0);
-    let results_at_least_one_b = results.get_odds(&vec![target_at_least_one_b.clone()]);
+    let results_at_least_one_b = results.get_odds(&[target_at_least_one_b]);
     assert_eq!(results_at_least_one_b, 12.0/16.0);
-    let results_exactly_one_a_and_at_least_one_b = results.get_odds(&vec![target_exactly_one_a, target_at_least_one_b]);
+    let results_exactly_one_a_and_at_least_one_b = results.get_odds(&[target_exactly_one_a, target_at_least_one_b]);
     assert_eq!(results_exactly_one_a_and_at_least_one_b, 6.0/16.0);
+}
+
+#[test]
+fn one_d20_meets_dc() {
+    let symbols = d20().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d20()], &policy).unwrap();
+
+    assert_eq!(results.meets_threshold(11, ThresholdOp::Ge, &symbols), 10.0/20.0);
+    assert_eq!(results.meets_threshold(20, ThresholdOp::Ge, &symbols), 1.0/20.0);
+    assert_eq!(results.meets_threshold(1, ThresholdOp::Ge, &symbols), 1.0);
+    assert_eq!(results.meets_threshold(21, ThresholdOp::Ge, &symbols), 0.0);
+    assert_eq!(results.meets_threshold(11, ThresholdOp::Gt, &symbols), 9.0/20.0);
+    assert_eq!(results.meets_threshold(11, ThresholdOp::Eq, &symbols), 1.0/20.0);
+    assert_eq!(results.meets_threshold(11, ThresholdOp::Le, &symbols), 11.0/20.0);
+    assert_eq!(results.meets_threshold(11, ThresholdOp::Lt, &symbols), 10.0/20.0);
+}
+
+#[test]
+fn one_d20_with_advantage() {
+    let symbols = d20().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d20()], &policy).unwrap();
+    let advantage = results.with_advantage(&symbols).unwrap();
+
+    assert_eq!(advantage.total, 20*20);
+    test_results_exactly(&advantage, &symbols, 1, 1.0/400.0);
+    test_results_exactly(&advantage, &symbols, 10, 19.0/400.0);
+    test_results_exactly(&advantage, &symbols, 20, 39.0/400.0);
+    assert_eq!(advantage.meets_threshold(15, ThresholdOp::Ge, &symbols), 1.0 - (14.0*14.0)/400.0);
+}
+
+#[test]
+fn one_d20_with_disadvantage() {
+    let symbols = d20().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d20()], &policy).unwrap();
+    let disadvantage = results.with_disadvantage(&symbols).unwrap();
+
+    assert_eq!(disadvantage.total, 20*20);
+    test_results_exactly(&disadvantage, &symbols, 1, 39.0/400.0);
+    test_results_exactly(&disadvantage, &symbols, 20, 1.0/400.0);
+    assert_eq!(disadvantage.meets_threshold(15, ThresholdOp::Ge, &symbols), (6.0*6.0)/400.0);
+}
+
+#[test]
+fn symbol_report_counts_dice_per_symbol() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let same_a_symbol = DieSymbol::new("  A ").unwrap();
+    let b_symbol = DieSymbol::new("B").unwrap();
+    let a_die = Die::new(vec![
+        DieSide::new(vec![ a_symbol.clone(), a_symbol.clone() ]),
+        DieSide::new(vec![ a_symbol.clone() ])
+    ]).unwrap();
+    let ab_die = Die::new(vec![
+        DieSide::new(vec![ same_a_symbol ]),
+        DieSide::new(vec![ b_symbol.clone() ])
+    ]).unwrap();
+
+    let report = symbol_report(&[a_die, ab_die, d4()]);
+
+    assert_eq!(report.len(), 3);
+    assert_eq!(report[&a_symbol], 2);
+    assert_eq!(report[&b_symbol], 1);
+    assert_eq!(report[&pip()], 1);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn resolve_two_d6s() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let symbols = d6().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let dice = [d6(), d6()];
+    let mut rng = StdRng::seed_from_u64(42);
+    let always = [RollTarget::at_least_n_of(2, &symbols), RollTarget::at_most_n_of(12, &symbols)];
+    let never = [RollTarget::at_least_n_of(13, &symbols)];
+    let sometimes = [RollTarget::at_least_n_of(8, &symbols)];
+
+    let mut passes = 0;
+    for _ in 0..10000 {
+        assert!(resolve(&dice, &policy, &always, &mut rng));
+        assert!(!resolve(&dice, &policy, &never, &mut rng));
+        if resolve(&dice, &policy, &sometimes, &mut rng) {
+            passes += 1;
+        }
+    }
+    let rate = passes as f64 / 10000.0;
+    assert!((rate - 15.0/36.0).abs() < 0.02);
+}
+
+#[test]
+fn first_success_position_d4_d6_d8() {
+    let symbols = d4().unique_symbols();
+    let positions = first_success_position(&[d4(), d6(), d8()], &symbols, 4);
+
+    assert_eq!(positions.len(), 4);
+    assert_eq!(positions[0], (0, 1.0/4.0));
+    assert_eq!(positions[1], (1, (3.0/4.0) * (3.0/6.0)));
+    assert_eq!(positions[2], (2, (3.0/4.0) * (3.0/6.0) * (5.0/8.0)));
+    assert_eq!(positions[3], (3, (3.0/4.0) * (3.0/6.0) * (3.0/8.0)));
+    let sum: f64 = positions.iter().map(|(_, p)| p).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn first_success_position_unreachable_threshold() {
+    let symbols = d4().unique_symbols();
+    let positions = first_success_position(&[d4(), d4()], &symbols, 5);
+
+    assert_eq!(positions, vec![ (0, 0.0), (1, 0.0), (2, 1.0) ]);
+}
+
+#[test]
+fn two_d4s_distribution() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    let distribution = results.distribution(&symbols);
+
+    assert_eq!(distribution, vec![
+        (2, 0.0625), (3, 0.125), (4, 0.1875), (5, 0.25), (6, 0.1875), (7, 0.125), (8, 0.0625)
+    ]);
+}
+
+#[test]
+fn sparkline_one_glyph_per_count() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let flat = RollProbabilities::new(&[d6()], &policy).unwrap();
+    let skewed = RollProbabilities::new(&[d4(), d4(), d4()], &policy).unwrap();
+
+    assert_eq!(flat.to_sparkline(&symbols), "██████");
+    assert_eq!(skewed.to_sparkline(&symbols).chars().count(), 10);
+    assert_eq!(skewed.to_sparkline(&symbols).chars().next(), Some('▁'));
+}
+
+#[test]
+fn two_custom_d4_weighted_target() {
+    let melee = DieSymbol::new("Melee").unwrap();
+    let heavy = DieSymbol::new("Heavy").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let all_symbols = vec![ melee.clone(), heavy.clone(), shield.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ melee.clone() ]),
+        DieSide::new(vec![ heavy.clone() ]),
+        DieSide::new(vec![ melee.clone(), heavy.clone() ]),
+        DieSide::new(vec![ shield.clone() ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&all_symbols);
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4], &policy).unwrap();
+    let weights = vec![ (melee, 1), (heavy, 2), (shield, -1) ];
+
+    assert_eq!(results.get_odds(&[RollTarget::weighted_at_least(3, &weights)]), 8.0/16.0);
+    assert_eq!(results.get_odds(&[RollTarget::weighted_at_least(0, &weights)]), 15.0/16.0);
+    assert_eq!(results.get_odds(&[RollTarget::weighted_at_least(-2, &weights)]), 1.0);
+    assert_eq!(results.get_odds(&[RollTarget::weighted_at_least(7, &weights)]), 0.0);
+}
+
+#[test]
+fn rolled_pool_of_custom_dice() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let b_symbol = DieSymbol::new("B").unwrap();
+    let a_symbol_vec = vec![ a_symbol.clone() ];
+    let both_symbols = vec![ a_symbol.clone(), b_symbol.clone() ];
+    let pool = RolledPool::new(vec![
+        DieSide::new(vec![ a_symbol.clone(), b_symbol.clone() ]),
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ a_symbol.clone() ])
+    ]);
+
+    assert_eq!(pool.count_of(&a_symbol_vec), 2);
+    assert_eq!(pool.count_of(&both_symbols), 3);
+    assert_eq!(pool.total(), 3);
+    assert_eq!(pool.symbols().get_count(&b_symbol), 1);
+    assert!(pool.meets(&RollTarget::exactly_n_of(2, &a_symbol_vec)));
+    assert!(!pool.meets(&RollTarget::at_least_n_of(4, &both_symbols)));
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn rolled_pool_roll_lands_on_die_sides() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let dice = [d4(), d8()];
+    for _ in 0..100 {
+        let pool = RolledPool::roll(&dice, &mut rng);
+        assert_eq!(pool.sides().len(), 2);
+        assert!((1..=4).contains(&pool.sides()[0].symbols().len()));
+        assert!((1..=8).contains(&pool.sides()[1].symbols().len()));
+    }
+}
+
+#[test]
+fn totals_only_matches_full_distribution() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let b_symbol = DieSymbol::new("B").unwrap();
+    let c_symbol = DieSymbol::new("C").unwrap();
+    let both_symbols = vec![ a_symbol.clone(), b_symbol.clone() ];
+    let all_symbols = vec![ a_symbol.clone(), b_symbol.clone(), c_symbol.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ a_symbol.clone() ]),
+        DieSide::new(vec![ b_symbol.clone(), c_symbol.clone() ]),
+        DieSide::new(vec![ a_symbol.clone(), b_symbol.clone() ]),
+        DieSide::new(vec![ c_symbol.clone() ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let dice = [custom_d4.clone(), custom_d4.clone(), custom_d4];
+    let policy = RollCollectionPolicy::take_highest_n_of(2, &all_symbols);
+
+    let full = RollProbabilities::new(&dice, &policy).unwrap();
+    let totals = RollProbabilities::new_totals_only(&dice, &policy, &both_symbols).unwrap();
+
+    assert_eq!(totals.total, full.total);
+    assert!(totals.occurrences.len() < full.occurrences.len());
+    assert_eq!(totals.distribution(&both_symbols), full.distribution(&both_symbols));
+}
+
+#[test]
+fn lerp_between_d4_and_two_d4s() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let one = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let two = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    let quarter = RollProbabilities::lerp(&one, &two, 0.25, &symbols);
+    assert_eq!(quarter.len(), 8);
+    assert_eq!(quarter[0], (1, 0.75 * 0.25));
+    assert_eq!(quarter[3], (4, 0.75 * 0.25 + 0.25 * 0.1875));
+    assert_eq!(quarter[7], (8, 0.25 * 0.0625));
+    let sum: f64 = quarter.iter().map(|(_, p)| p).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+
+    assert_eq!(RollProbabilities::lerp(&one, &two, -1.0, &symbols)[0], (1, 0.25));
+    assert_eq!(RollProbabilities::lerp(&one, &two, 2.0, &symbols)[0], (1, 0.0));
+}
+
+#[test]
+// anydice.com
+// output 2d4
+fn two_d4s_compare_to_reference() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+    let reference = vec![ 
+        (2, 0.0625), (3, 0.125), (4, 0.1875), (5, 0.25), (6, 0.1875), (7, 0.125), (8, 0.0625)
+    ];
+    assert!(results.compare_to_reference(&reference, &symbols, 1e-9).is_empty());
+
+    let off_reference = vec![ 
+        (1, 0.01), (2, 0.0625), (3, 0.125), (4, 0.1875), (5, 0.25), (6, 0.1875), (7, 0.125)
+    ];
+    let differences = results.compare_to_reference(&off_reference, &symbols, 1e-9);
+    assert_eq!(differences, vec![ (1, 0.01), (8, 0.0625) ]);
+    assert!(results.compare_to_reference(&off_reference, &symbols, 0.1).is_empty());
+}
+
+fn numbered_d6() -> Die {
+    let sides = 
+        (1..=6)
+        .map(|i| DieSide::new(vec![ DieSymbol::new(i.to_string()).unwrap() ]))
+        .collect();
+    Die::new(sides).unwrap()
+}
+
+#[test]
+// anydice.com
+// output [count {6} in 3d6]
+fn count_sixes_in_three_d6s() {
+    let six = vec![ DieSymbol::new("6").unwrap() ];
+    let results = RollProbabilities::count_in(&[numbered_d6(), numbered_d6(), numbered_d6()], &six).unwrap();
+
+    assert_eq!(results.total, 6*6*6);
+    test_results_exactly(&results, &six, 0, 125.0/216.0);
+    test_results_exactly(&results, &six, 1, 75.0/216.0);
+    test_results_exactly(&results, &six, 2, 15.0/216.0);
+    test_results_exactly(&results, &six, 3, 1.0/216.0);
+}
+
+#[test]
+// anydice.com
+// output [count {5,6} in 2d6]
+fn count_fives_and_sixes_in_two_d6s() {
+    let five_or_six = vec![ DieSymbol::new("5").unwrap(), DieSymbol::new("6").unwrap() ];
+    let results = RollProbabilities::count_in(&[numbered_d6(), numbered_d6()], &five_or_six).unwrap();
+
+    test_results_exactly(&results, &five_or_six, 0, 16.0/36.0);
+    test_results_exactly(&results, &five_or_six, 1, 16.0/36.0);
+    test_results_exactly(&results, &five_or_six, 2, 4.0/36.0);
+}
+
+#[test]
+fn count_in_pip_dice_caps_each_die_at_one() {
+    let symbols = d6().unique_symbols();
+    let results = RollProbabilities::count_in(&[d6(), d6(), d6()], &symbols).unwrap();
+
+    test_results_exactly(&results, &symbols, 3, 1.0);
+    assert!(RollProbabilities::count_in(&[d6()], &[]).is_err());
+}
+
+#[test]
+fn two_custom_d4_drop_showing_curse() {
+    let hit = DieSymbol::new("Hit").unwrap();
+    let curse = DieSymbol::new("Curse").unwrap();
+    let hit_vec = vec![ hit.clone() ];
+    let curse_vec = vec![ curse.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ hit.clone() ]),
+        DieSide::new(vec![ hit.clone(), hit.clone() ]),
+        DieSide::new(vec![ hit.clone(), curse.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::drop_showing(&curse_vec, &hit_vec);
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4], &policy).unwrap();
+
+    assert_eq!(results.total, 4*4);
+    test_results_exactly(&results, &hit_vec, 0, 4.0/16.0);
+    test_results_exactly(&results, &hit_vec, 1, 4.0/16.0);
+    test_results_exactly(&results, &hit_vec, 2, 5.0/16.0);
+    test_results_exactly(&results, &hit_vec, 3, 2.0/16.0);
+    test_results_exactly(&results, &hit_vec, 4, 1.0/16.0);
+}
+
+#[test]
+fn two_custom_d4_distinct_count_distribution() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let b_symbol = DieSymbol::new("B").unwrap();
+    let both_symbols = vec![ a_symbol.clone(), b_symbol.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ a_symbol.clone() ]),
+        DieSide::new(vec![ b_symbol.clone() ]),
+        DieSide::new(vec![ a_symbol.clone(), b_symbol.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&both_symbols);
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4], &policy).unwrap();
+
+    let distinct = results.distinct_count_distribution();
+
+    assert_eq!(distinct, vec![ (0, 1.0/16.0), (1, 6.0/16.0), (2, 9.0/16.0) ]);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn budget_falls_back_to_sampling() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let mut rng = StdRng::seed_from_u64(11);
+
+    let exact = RollProbabilities::new_with_budget(&[d4(), d4()], &policy, 16, &mut rng).unwrap();
+    assert!(exact.is_exact());
+    assert_eq!(exact.probabilities().total, 16);
+    test_results_exactly(exact.probabilities(), &symbols, 5, 0.25);
+
+    let approximate = RollProbabilities::new_with_budget(&[d4(), d4()], &policy, 15, &mut rng).unwrap();
+    assert!(!approximate.is_exact());
+    assert_eq!(approximate.probabilities().total, 15);
+
+    let six_d6s = [d6(), d6(), d6(), d6(), d6(), d6()];
+    let sampled = RollProbabilities::new_with_budget(&six_d6s, &policy, 20000, &mut rng).unwrap();
+    assert!(!sampled.is_exact());
+    let targets = [RollTarget::at_least_n_of(24, &symbols)];
+    let odds = sampled.probabilities().get_odds(&targets);
+    let exact_odds = RollProbabilities::new(&six_d6s, &policy).unwrap().get_odds(&targets);
+    assert!((odds - exact_odds).abs() < 0.02);
+
+    assert!(RollProbabilities::new_with_budget(&[d4()], &policy, 0, &mut rng).is_err());
+    assert!(RollProbabilities::new_with_budget(&[], &policy, 10, &mut rng).is_err());
+}
+
+#[test]
+fn three_d6s_all_distinct() {
+    assert_eq!(all_distinct_probability(&[d6(), d6(), d6()], &pip()), 120.0/216.0);
+    assert_eq!(all_distinct_probability(&[d4(), d4(), d4(), d4(), d4()], &pip()), 0.0);
+    assert_eq!(all_distinct_probability(&[d4(), d8()], &pip()), 28.0/32.0);
+    assert_eq!(all_distinct_probability(&[d4()], &pip()), 1.0);
+}
+
+#[test]
+fn two_custom_d4_conditional_modifier() {
+    let hit = DieSymbol::new("Hit").unwrap();
+    let bane = DieSymbol::new("Bane").unwrap();
+    let hit_vec = vec![ hit.clone() ];
+    let bane_vec = vec![ bane.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ hit.clone() ]),
+        DieSide::new(vec![ hit.clone(), hit.clone() ]),
+        DieSide::new(vec![ bane.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let dice = [custom_d4.clone(), custom_d4];
+    let policy = RollCollectionPolicy::collect_all(&hit_vec);
+
+    let penalized = RollProbabilities::new_with_conditional_modifier(&dice, &policy, &bane_vec, 1, -2, &hit_vec).unwrap();
+    assert_eq!(penalized.total, 16);
+    // totals below zero stay apart from zero
+    assert_eq!(penalized.distribution(&hit_vec), vec![ 
+        (0, 3.0/16.0), (1, 2.0/16.0), (2, 3.0/16.0), (3, 2.0/16.0), (4, 1.0/16.0)
+    ]);
+    assert_eq!(penalized.value_distribution(), vec![ 
+        (-2, 3.0/16.0), (-1, 2.0/16.0), (0, 3.0/16.0), (1, 2.0/16.0), (2, 3.0/16.0), (3, 2.0/16.0), (4, 1.0/16.0)
+    ]);
+    assert_eq!(penalized.get_odds(&[ RollTarget::exactly_n_of(0, &hit_vec) ]), 3.0/16.0);
+    assert_eq!(penalized.get_odds(&[ RollTarget::at_most_n_of(0, &hit_vec) ]), 8.0/16.0);
+    assert_eq!(penalized.mean(&hit_vec), 10.0/16.0);
+
+    let double_bane_bonus = RollProbabilities::new_with_conditional_modifier(&dice, &policy, &bane_vec, 2, 3, &hit_vec).unwrap();
+    assert_eq!(double_bane_bonus.distribution(&hit_vec), vec![ 
+        (0, 3.0/16.0), (1, 4.0/16.0), (2, 5.0/16.0), (3, 3.0/16.0), (4, 1.0/16.0)
+    ]);
+}
+
+#[test]
+fn achievable_totals_skip_gaps() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let a_symbol_vec = vec![ a_symbol.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ a_symbol.clone(), a_symbol.clone(), a_symbol.clone() ])
+    ];
+    let custom_d2 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&a_symbol_vec);
+    let results = RollProbabilities::new(&[custom_d2.clone(), custom_d2], &policy).unwrap();
+
+    assert_eq!(results.achievable_totals(&a_symbol_vec), vec![ 0, 3, 6 ]);
+    assert_eq!(results.achievable_totals(&[]), vec![ 0 ]);
+}
+
+#[test]
+fn one_d4_payoff_matrix_two_d4() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results1 = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let results2 = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    let matrix = results1.payoff_matrix(&results2, &symbols);
+    let rows = results1.achievable_totals(&symbols);
+    let cols = results2.achievable_totals(&symbols);
+
+    let mut wins = 0.0;
+    let mut ties = 0.0;
+    let mut total = 0.0;
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, p) in row.iter().enumerate() {
+            total += p;
+            if rows[i] > cols[j] { wins += p; }
+            if rows[i] == cols[j] { ties += p; }
+        }
+    }
+    let compare = results1.roll_against(&results2).unwrap();
+    assert_eq!(total, 1.0);
+    assert_eq!(wins, compare.win_odds());
+    assert_eq!(ties, compare.tie_odds());
+    assert_eq!(matrix[0][0], 0.25 * 0.0625);
+}
+
+#[test]
+fn pool_distribution_matches_full_enumeration() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let dice = [d4(), d6(), d8(), d10()];
+    let mut pool = PoolDistribution::new();
+    for die in dice.iter() {
+        pool.add_die(die, &symbols).unwrap();
+    }
+    let results = RollProbabilities::new(&dice, &policy).unwrap();
+
+    assert_eq!(pool.probabilities.total, results.total);
+    assert_eq!(pool.probabilities.distribution(&symbols), results.distribution(&symbols));
+    for n in 4..=28 {
+        let target = [RollTarget::at_least_n_of(n, &symbols)];
+        assert_eq!(pool.odds(&target), results.get_odds(&target));
+    }
+}
+
+#[test]
+fn pool_distribution_ignores_unlisted_symbols() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let b_symbol = DieSymbol::new("B").unwrap();
+    let a_symbol_vec = vec![ a_symbol.clone() ];
+    let b_symbol_vec = vec![ b_symbol.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ a_symbol.clone() ]),
+        DieSide::new(vec![ b_symbol.clone() ]),
+        DieSide::new(vec![ a_symbol.clone(), b_symbol.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let mut pool = PoolDistribution::new();
+    pool.add_die(&custom_d4, &a_symbol_vec).unwrap();
+    pool.add_die(&custom_d4, &a_symbol_vec).unwrap();
+
+    assert_eq!(pool.odds(&[RollTarget::exactly_n_of(1, &a_symbol_vec)]), 0.5);
+    assert_eq!(pool.odds(&[RollTarget::exactly_n_of(0, &b_symbol_vec)]), 1.0);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn two_d4s_to_json() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&results.to_json(&symbols)).unwrap();
+
+    assert_eq!(json["total"], 16);
+    let distribution = json["distribution"].as_array().unwrap();
+    assert_eq!(distribution.len(), 7);
+    assert_eq!(distribution[3]["count"], 5);
+    assert_eq!(distribution[3]["probability"], 0.25);
+    assert_eq!(distribution[3]["occurrences"], 4);
+}
+
+#[test]
+fn two_d4s_to_csv() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    let csv = results.to_csv(&symbols);
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[0], "count,probability,occurrences");
+    assert_eq!(lines[1], "2,0.0625,1");
+    assert_eq!(lines[4], "5,0.25,4");
+}
+
+#[test]
+fn pip_die_compare_mixed_symbol_die() {
+    let shield = DieSymbol::new("Shield").unwrap();
+    let pips = vec![ pip() ];
+    let all_symbols = vec![ pip(), shield.clone() ];
+    let shield_d4 = Die::new(vec![
+        DieSide::new(vec![ pip(), shield.clone(), shield.clone() ]),
+        DieSide::new(vec![ pip(), pip(), shield.clone() ]),
+        DieSide::new(vec![ pip(), pip(), pip(), shield.clone() ]),
+        DieSide::new(vec![ pip(), pip(), pip(), pip() ])
+    ]).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&all_symbols);
+    let d4_results = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let shield_results = RollProbabilities::new(&[shield_d4], &policy).unwrap();
+
+    let naive = d4_results.roll_against(&shield_results).unwrap();
+    assert_eq!(naive.win_odds(), 2.0/16.0);
+    assert_eq!(naive.tie_odds(), 4.0/16.0);
+
+    let by_pips = d4_results.roll_against_symbols(&shield_results, &pips).unwrap();
+    let d4_vs_d4 = d4_results.roll_against(&d4_results).unwrap();
+    assert_eq!(by_pips.win_odds(), d4_vs_d4.win_odds());
+    assert_eq!(by_pips.tie_odds(), d4_vs_d4.tie_odds());
+    assert_eq!(by_pips.loss_odds(), d4_vs_d4.loss_odds());
+}
+
+#[test]
+fn three_d6s_reroll_failures() {
+    let symbols = d6().unique_symbols();
+    let results = RollProbabilities::new_reroll_failures(&[d6(), d6(), d6()], &symbols, 5, &symbols).unwrap();
+
+    assert_eq!(results.total, 36*36*36);
+    test_results_exactly(&results, &symbols, 18, (10.0*10.0*10.0)/(36.0*36.0*36.0));
+    test_results_exactly(&results, &symbols, 3, (4.0*4.0*4.0)/(36.0*36.0*36.0));
+    test_results_exactly(&results, &symbols, 4, (3.0*4.0*4.0*4.0)/(36.0*36.0*36.0));
+
+    let single = RollProbabilities::new_reroll_failures(&[d6()], &symbols, 5, &symbols).unwrap();
+    assert_eq!(single.distribution(&symbols), vec![
+        (1, 4.0/36.0), (2, 4.0/36.0), (3, 4.0/36.0), (4, 4.0/36.0), (5, 10.0/36.0), (6, 10.0/36.0)
+    ]);
+}
+
+#[test]
+fn empty_probabilities() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let d4_results = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let empty = RollProbabilities::default();
+
+    assert_eq!(empty.total, 0);
+    assert_eq!(empty.get_odds(&[RollTarget::at_least_n_of(0, &symbols)]), 0.0);
+    assert_eq!(empty.meets_threshold(0, ThresholdOp::Ge, &symbols), 0.0);
+    assert!(empty.distribution(&symbols).is_empty());
+    assert_eq!(empty.to_sparkline(&symbols), "");
+    assert_eq!(empty.roll_against(&d4_results).unwrap().win_odds(), 0.0);
+    assert_eq!(empty.with_advantage(&symbols).unwrap().total, 0);
+}
+
+#[test]
+fn party_all_pass() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let d4_results = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let two_d4_results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    assert_eq!(all_pass(&[]), 1.0);
+    assert_eq!(all_pass(&[(&d4_results, vec![ RollTarget::at_least_n_of(2, &symbols) ])]), 0.75);
+    assert_eq!(all_pass(&[
+        (&d4_results, vec![ RollTarget::at_least_n_of(2, &symbols) ]),
+        (&two_d4_results, vec![ RollTarget::exactly_n_of(5, &symbols) ]),
+        (&d4_results, vec![ RollTarget::at_most_n_of(2, &symbols) ])
+    ]), 0.75 * 0.25 * 0.5);
+}
+
+#[test]
+fn party_any_pass() {
+    let symbols = d4().unique_symbols();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let d4_results = RollProbabilities::new(&[d4()], &policy).unwrap();
+    let two_d4_results = RollProbabilities::new(&[d4(), d4()], &policy).unwrap();
+
+    assert_eq!(any_pass(&[]), 0.0);
+    assert_eq!(any_pass(&[(&d4_results, vec![ RollTarget::at_least_n_of(2, &symbols) ])]), 0.75);
+    assert_eq!(any_pass(&[
+        (&d4_results, vec![ RollTarget::exactly_n_of(4, &symbols) ]),
+        (&two_d4_results, vec![ RollTarget::exactly_n_of(5, &symbols) ]),
+        (&d4_results, vec![ RollTarget::at_most_n_of(2, &symbols) ])
+    ]), 1.0 - (0.75 * 0.75 * 0.5));
+    assert_eq!(any_pass(&[(&d4_results, vec![ RollTarget::at_least_n_of(5, &symbols) ])]), 0.0);
+}
+
+#[test]
+fn d6_minus_d6_difference_distribution() {
+    let differences = difference_distribution(&d6(), &d6(), &pip());
+
+    let expected: Vec<(i64, f64)> = 
+        (-5..=5)
+        .map(|d: i64| (d, ((6 - d.abs()) as f64) / 36.0))
+        .collect();
+    assert_eq!(differences, expected);
+}
+
+#[test]
+fn d4_minus_d8_difference_distribution() {
+    let differences = difference_distribution(&d4(), &d8(), &pip());
+
+    assert_eq!(differences.first(), Some(&(-7, 1.0/32.0)));
+    assert_eq!(differences.last(), Some(&(3, 1.0/32.0)));
+    assert_eq!(differences.iter().find(|(d, _)| *d == -2), Some(&(-2, 4.0/32.0)));
+}
+
+#[test]
+fn two_custom_dice_take_highest_distinct() {
+    let a_symbol = DieSymbol::new("A").unwrap();
+    let b_symbol = DieSymbol::new("B").unwrap();
+    let both_symbols = vec![ a_symbol.clone(), b_symbol.clone() ];
+    let a_vec = vec![ a_symbol.clone() ];
+    let b_vec = vec![ b_symbol.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ a_symbol.clone(), a_symbol.clone() ]),
+        DieSide::new(vec![ a_symbol.clone(), b_symbol.clone() ])
+    ];
+    let custom_die = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::take_highest_distinct_n(1, &both_symbols);
+    let results = RollProbabilities::new(&[custom_die.clone(), custom_die], &policy).unwrap();
+
+    assert_eq!(results.total, 2*2);
+    test_results_exactly(&results, &a_vec, 2, 1.0/4.0);
+    test_results_exactly(&results, &a_vec, 1, 3.0/4.0);
+    test_results_exactly(&results, &b_vec, 1, 3.0/4.0);
+    test_results_exactly(&results, &b_vec, 0, 1.0/4.0);
+}
+
+#[test]
+fn d8_against_d4_margin_variance() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let d8_result = RollProbabilities::new(&[d8()], &policy).unwrap();
+    let d4_result = RollProbabilities::new(&[d4()], &policy).unwrap();
+
+    // var(d8) = 63/12, var(d4) = 15/12
+    let variance = d8_result.margin_variance(&d4_result, &symbols).unwrap();
+    assert!((variance - 78.0 / 12.0).abs() < 1e-12);
+    let reversed = d4_result.margin_variance(&d8_result, &symbols).unwrap();
+    assert!((reversed - variance).abs() < 1e-12);
+    assert_eq!(d8_result.margin_variance(&RollProbabilities::empty(), &symbols).unwrap(), 0.0);
+}
+
+#[test]
+fn pools_within_budget_lists_multisets() {
+    let menu = vec![ (d4(), 1), (d6(), 2), (d20(), 5) ];
+
+    let pools = pools_within_budget(&menu, 3).unwrap();
+    let side_counts: Vec<Vec<usize>> = pools.iter()
+        .map(|pool| pool.iter().map(|die| die.sides().len()).collect())
+        .collect();
+
+    assert_eq!(side_counts, vec![
+        vec![ 4 ],
+        vec![ 4, 4 ],
+        vec![ 4, 4, 4 ],
+        vec![ 4, 6 ],
+        vec![ 6 ] ]);
+    assert!(pools_within_budget(&menu, 0).unwrap().is_empty());
+    assert!(pools_within_budget(&[ (d4(), 1), (d6(), 0) ], 3).is_err());
+}
+
+#[test]
+fn pools_within_budget_caps_result_size() {
+    let menu: Vec<(Die, usize)> = (0..10).map(|_| (d4(), 1)).collect();
+
+    // 19,447 pools of up to seven dice fit the budget, 8,007 of up to six
+    assert!(pools_within_budget(&menu, 7).is_err());
+    assert_eq!(pools_within_budget(&menu, 6).unwrap().len(), 8_007);
+}
+
+#[test]
+fn two_d6_tail_expectation() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+
+    // (8*5 + 9*4 + 10*3 + 11*2 + 12*1) / 15
+    let expected = results.tail_expectation(8, &symbols);
+    assert!((expected - 140.0 / 15.0).abs() < 1e-12);
+    assert!((results.tail_expectation(0, &symbols) - 7.0).abs() < 1e-12);
+    assert_eq!(results.tail_expectation(13, &symbols), 0.0);
+}
+
+#[test]
+fn two_custom_d4_wild_counts_as_any_symbol() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let wild = DieSymbol::new("Wild").unwrap();
+    let swords = vec![ sword.clone() ];
+    let shields = vec![ shield.clone() ];
+    let all_symbols = vec![ sword.clone(), shield.clone(), wild.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ wild.clone() ]),
+        DieSide::new(vec![ shield.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&all_symbols);
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4], &policy).unwrap();
+
+    let two_swords = [ RollTarget::at_least_n_of(2, &swords) ];
+    assert_eq!(results.get_odds(&two_swords), 1.0/16.0);
+    assert_eq!(results.get_odds_with_wild(&two_swords, &wild), 4.0/16.0);
+
+    // a single wild can fill only one of the two targets
+    let sword_and_shield = [ RollTarget::at_least_n_of(1, &swords), RollTarget::at_least_n_of(1, &shields) ];
+    assert_eq!(results.get_odds(&sword_and_shield), 2.0/16.0);
+    assert_eq!(results.get_odds_with_wild(&sword_and_shield, &wild), 7.0/16.0);
+
+    // wilds may be left unused
+    let no_swords = [ RollTarget::exactly_n_of(0, &swords) ];
+    assert_eq!(results.get_odds_with_wild(&no_swords, &wild), 9.0/16.0);
+}
+
+#[test]
+fn d6_marginal_gain() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let at_least_8 = vec![ RollTarget::at_least_n_of(8, &symbols) ];
+
+    let gain = marginal_gain(&[d6()], &d6(), &policy, &at_least_8).unwrap();
+    assert!((gain - 15.0 / 36.0).abs() < 1e-12);
+}
+
+#[test]
+fn empty_pool_marginal_gain() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let at_least_5 = vec![ RollTarget::at_least_n_of(5, &symbols) ];
+    let at_least_0 = vec![ RollTarget::at_least_n_of(0, &symbols) ];
+
+    let gain = marginal_gain(&[], &d6(), &policy, &at_least_5).unwrap();
+    assert!((gain - 2.0 / 6.0).abs() < 1e-12);
+    assert_eq!(marginal_gain(&[], &d6(), &policy, &at_least_0).unwrap(), 0.0);
+}
+
+#[test]
+fn two_d6_and_two_d20_coefficient_of_variation() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let two_d6s = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+    let two_d20s = RollProbabilities::new(&[d20(), d20()], &policy).unwrap();
+
+    let d6_spread = two_d6s.coefficient_of_variation(&symbols);
+    let d20_spread = two_d20s.coefficient_of_variation(&symbols);
+    assert!((d6_spread - (35.0_f64 / 6.0).sqrt() / 7.0).abs() < 1e-12);
+    assert!((d20_spread - (399.0_f64 / 6.0).sqrt() / 21.0).abs() < 1e-12);
+    assert!(d20_spread > d6_spread);
+    assert_eq!(RollProbabilities::empty().coefficient_of_variation(&symbols), 0.0);
+}
+
+#[test]
+fn two_d6_trim_tails() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+
+    let trimmed = results.trim_tails(&symbols, 0.05);
+
+    assert_eq!(trimmed.len(), 9);
+    assert_eq!(trimmed[0].0, 3);
+    assert!((trimmed[0].1 - 3.0/36.0).abs() < 1e-12);
+    assert_eq!(trimmed[8].0, 11);
+    assert!((trimmed[8].1 - 3.0/36.0).abs() < 1e-12);
+    assert!((trimmed.iter().map(|(_, p)| p).sum::<f64>() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn custom_die_trim_tails_merges_inner_counts() {
+    let sides = vec![
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ pip() ]),
+        DieSide::new(vec![ pip(), pip() ]),
+        DieSide::new(vec![ pip(), pip() ])
+    ];
+    let custom_die = Die::new(sides).unwrap();
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[custom_die], &policy).unwrap();
+
+    // 1 is equally near 0 and 2, so it merges into the lower count
+    let trimmed = results.trim_tails(&symbols, 0.3);
+    assert_eq!(trimmed.len(), 2);
+    assert_eq!(trimmed[0].0, 0);
+    assert!((trimmed[0].1 - 0.6).abs() < 1e-12);
+    assert_eq!(trimmed[1], (2, 0.4));
+
+    // nothing is retained, so everything merges into the most likely count
+    let all_merged = results.trim_tails(&symbols, 0.5);
+    assert_eq!(all_merged.len(), 1);
+    assert_eq!(all_merged[0].0, 0);
+    assert!((all_merged[0].1 - 1.0).abs() < 1e-12);
+    assert!(RollProbabilities::empty().trim_tails(&symbols, 0.1).is_empty());
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn sample_follows_policy_and_distribution() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::take_highest_n_of(1, &symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+    let mut rng = StdRng::seed_from_u64(5);
+
+    let samples: Vec<usize> = (0..3600)
+        .map(|_| results.sample(&mut rng).get_count(&pip()))
+        .collect();
+
+    assert!(samples.iter().all(|c| (1..=6).contains(c)));
+    // keeping the higher of 2d6 averages 161/36
+    let average = samples.iter().sum::<usize>() as f64 / samples.len() as f64;
+    assert!((average - 161.0 / 36.0).abs() < 0.1);
+    assert_eq!(RollProbabilities::empty().sample(&mut rng).total_count(), 0);
+}
+
+#[test]
+fn exploding_d4_stops_at_max_depth() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let max_face = vec![ pip(); 4 ];
+
+    let unmodified = RollModifier::Explode { on: &max_face, max_depth: 0 };
+    let results = RollProbabilities::new_with_roll_modifier(&[d4()], &policy, &unmodified).unwrap();
+    assert_eq!(results.total, 4);
+    test_results_exactly(&results, &symbols, 4, 1.0/4.0);
+
+    let explode_twice = RollModifier::Explode { on: &max_face, max_depth: 2 };
+    let results = RollProbabilities::new_with_roll_modifier(&[d4()], &policy, &explode_twice).unwrap();
+    assert_eq!(results.total, 4*4*4);
+    test_results_exactly(&results, &symbols, 1, 16.0/64.0);
+    test_results_exactly(&results, &symbols, 4, 0.0);
+    test_results_exactly(&results, &symbols, 5, 4.0/64.0);
+    test_results_exactly(&results, &symbols, 8, 0.0);
+    test_results_exactly(&results, &symbols, 9, 1.0/64.0);
+    // the third four is kept rather than exploding again
+    test_results_exactly(&results, &symbols, 12, 1.0/64.0);
+    test_results_exactly(&results, &symbols, 13, 0.0);
+}
+
+#[test]
+fn exploding_custom_dice_take_highest() {
+    let star = DieSymbol::new("Star").unwrap();
+    let stars = vec![ star.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ star.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let coin = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::take_highest_n_of(1, &stars);
+    let explode = RollModifier::Explode { on: &stars, max_depth: 1 };
+
+    let results = RollProbabilities::new_with_roll_modifier(&[coin.clone(), coin], &policy, &explode).unwrap();
+
+    // each coin shows no stars 2/4, one star 1/4 and two stars 1/4
+    assert_eq!(results.total, 4*4);
+    test_results_exactly(&results, &stars, 0, 4.0/16.0);
+    test_results_exactly(&results, &stars, 1, 5.0/16.0);
+    test_results_exactly(&results, &stars, 2, 7.0/16.0);
+}
+
+#[test]
+fn exploding_too_deep_is_rejected() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let max_face = vec![ pip(); 10 ];
+
+    // each unexploded side stands for 10^20 rerolls, which can't be counted
+    let too_deep = RollModifier::Explode { on: &max_face, max_depth: 20 };
+    assert!(RollProbabilities::new_with_roll_modifier(&[ d10() ], &policy, &too_deep).is_err());
+    let shallow = RollModifier::Explode { on: &max_face, max_depth: 3 };
+    assert_eq!(RollProbabilities::new_with_roll_modifier(&[ d10() ], &policy, &shallow).unwrap().total, 10_000);
+}
+
+#[test]
+fn exploding_keeps_side_values() {
+    let sides = vec![
+        DieSide::with_value(vec![ pip() ], 5),
+        DieSide::with_value(vec![ ], 0),
+        DieSide::new(vec![ ]) ];
+    let die = Die::new(sides).unwrap();
+    let on = vec![ pip() ];
+
+    let exploded = RollModifier::Explode { on: &on, max_depth: 1 }.weighted_sides(&die).unwrap();
+
+    assert_eq!(exploded.len(), 5);
+    assert_eq!(exploded[0].0.value(), Some(10));
+    assert_eq!(exploded[1].0.value(), Some(5));
+    assert_eq!(exploded[2].0.value(), Some(5));
+    assert_eq!(exploded[2].0.symbols(), &[ pip() ]);
+    assert_eq!(exploded[3], (DieSide::with_value(vec![ ], 0), 3));
+    assert_eq!(exploded[4], (DieSide::new(vec![ ]), 3));
+}
+
+#[test]
+fn d4_and_d8_reroll_once_below_take_highest() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::take_highest_n_of(1, &symbols);
+    let reroll = RollRerollPolicy::once_below(3, &symbols);
+
+    let results = RollProbabilities::new_with_reroll(&[d4(), d8()], &policy, &reroll).unwrap();
+
+    // the d4 rerolls into its own four sides and the d8 into its own eight
+    assert_eq!(results.total, (4*4)*(8*8));
+    test_results_exactly(&results, &symbols, 1, (2.0/16.0)*(2.0/64.0));
+    test_results_exactly(&results, &symbols, 8, 10.0/64.0);
+    assert_eq!(results.get_odds(&[RollTarget::at_most_n_of(4, &symbols)]), 24.0/64.0);
+}
+
+#[test]
+fn loaded_d4_honours_weights() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let loaded_d4 = Die::new_weighted(vec![
+        (DieSide::new(vec![ pip() ]), 1),
+        (DieSide::new(vec![ pip(), pip() ]), 1),
+        (DieSide::new(vec![ pip(), pip(), pip() ]), 1),
+        (DieSide::new(vec![ pip(), pip(), pip(), pip() ]), 5) ]).unwrap();
+
+    let results = RollProbabilities::new(&[loaded_d4.clone(), d4()], &policy).unwrap();
+    assert_eq!(results.total, 8*4);
+    test_results_exactly(&results, &symbols, 2, 1.0/32.0);
+    test_results_exactly(&results, &symbols, 8, 5.0/32.0);
+
+    let totals = RollProbabilities::new_totals_only(&[loaded_d4.clone(), d4()], &policy, &symbols).unwrap();
+    assert_eq!(totals.get_odds(&[RollTarget::exactly_n_of(8, &symbols)]), 5.0/32.0);
+
+    let mut pool = PoolDistribution::new();
+    pool.add_die(&loaded_d4, &symbols).unwrap();
+    pool.add_die(&d4(), &symbols).unwrap();
+    assert_eq!(pool.odds(&[RollTarget::exactly_n_of(8, &symbols)]), 5.0/32.0);
+
+    let differences = difference_distribution(&loaded_d4, &d4(), &pip());
+    assert_eq!(differences.last(), Some(&(3, 5.0/32.0)));
+
+    let positions = first_success_position(&[loaded_d4], &symbols, 4);
+    assert_eq!(positions, vec![ (0, 5.0/8.0), (1, 3.0/8.0) ]);
+}
+
+#[test]
+fn valued_symbols_value_distribution() {
+    let five = DieSymbol::new_valued("Five", 5).unwrap();
+    let symbols = vec![ pip(), five.clone() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let die = Die::new(vec![
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ pip() ]),
+        DieSide::new(vec![ five.clone() ]),
+        DieSide::new(vec![ five, pip() ]) ]).unwrap();
+
+    let results = RollProbabilities::new(&[die.clone(), die], &policy).unwrap();
+    let values = results.value_distribution();
+
+    assert_eq!(values.len(), 9);
+    assert_eq!(values[0], (0, 1.0/16.0));
+    assert_eq!(values.iter().find(|(v, _)| *v == 6), Some(&(6, 4.0/16.0)));
+    assert_eq!(values[8], (12, 1.0/16.0));
+    // counting is unchanged by symbol values
+    test_results_exactly(&results, &symbols, 4, 1.0/16.0);
+}
+
+#[test]
+fn two_d6_mean_variance_std_dev() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+
+    assert!((results.mean(&symbols) - 7.0).abs() < 1e-12);
+    assert!((results.variance(&symbols) - 35.0 / 6.0).abs() < 1e-12);
+    assert!((results.std_dev(&symbols) - (35.0_f64 / 6.0).sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn take_highest_mean_and_degenerate_variance() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::take_highest_n_of(1, &symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+    let empty = RollProbabilities::empty();
+    let coin = Die::new(vec![ DieSide::new(vec![ pip() ]), DieSide::new(vec![ pip() ]) ]).unwrap();
+    let certain = RollProbabilities::new(&[coin], &RollCollectionPolicy::collect_all(&symbols)).unwrap();
+
+    assert!((results.mean(&symbols) - 161.0 / 36.0).abs() < 1e-12);
+    assert_eq!(empty.mean(&symbols), 0.0);
+    assert_eq!(empty.variance(&symbols), 0.0);
+    assert_eq!(empty.std_dev(&symbols), 0.0);
+    assert_eq!(certain.mean(&symbols), 1.0);
+    assert_eq!(certain.std_dev(&symbols), 0.0);
+}
+
+#[test]
+fn two_d6_cumulative_and_percentile() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+
+    let cumulative = results.cumulative(&symbols);
+    assert_eq!(cumulative.len(), 11);
+    assert_eq!(cumulative[5], (7, 21.0/36.0));
+    assert_eq!(cumulative[10], (12, 1.0));
+
+    assert_eq!(results.percentile(&symbols, 0.0), 2);
+    assert_eq!(results.percentile(&symbols, 0.5), 7);
+    assert_eq!(results.percentile(&symbols, 0.9), 10);
+    assert_eq!(results.percentile(&symbols, 1.0), 12);
+    assert_eq!(results.percentile(&symbols, 1.5), 12);
+    assert_eq!(RollProbabilities::empty().percentile(&symbols, 0.5), 0);
+    assert!(RollProbabilities::empty().cumulative(&symbols).is_empty());
+}
+
+#[test]
+fn custom_d4_outcomes() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let swords = vec![ sword.clone() ];
+    let both_symbols = vec![ sword.clone(), shield.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ sword.clone(), sword.clone() ]),
+        DieSide::new(vec![ shield.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&both_symbols);
+    let results = RollProbabilities::new(&[custom_d4], &policy).unwrap();
+
+    assert_eq!(results.outcomes().count(), 4);
+    let shield_outcome = results.outcomes().find(|(outcome, _)| outcome.symbol_count(&shield) == 1).unwrap();
+    assert_eq!(shield_outcome.1, 0.25);
+
+    let sorted = results.outcomes_sorted_by_count(&swords);
+    let sword_counts: Vec<usize> = sorted.iter().map(|(outcome, _)| outcome.symbol_count(&sword)).collect();
+    assert_eq!(sword_counts, vec![ 0, 0, 1, 2 ]);
+    let totals: Vec<usize> = sorted.iter().skip(2).map(|(outcome, _)| outcome.total_count()).collect();
+    assert_eq!(totals, vec![ 1, 2 ]);
+    assert_eq!(sorted[3].0.total_value(), 2);
+    assert_eq!(sorted[3].0.count_of(&both_symbols), 2);
+    assert_eq!(RollProbabilities::empty().outcomes().count(), 0);
+}
+
+#[test]
+fn two_d6_render_histogram() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+
+    let histogram = results.render_histogram(&symbols, 12);
+    let lines: Vec<&str> = histogram.lines().collect();
+
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[0], " 2 | ##             2.78%");
+    assert_eq!(lines[5], " 7 | ############  16.67%");
+    assert_eq!(lines[10], "12 | ##             2.78%");
+    assert_eq!(RollProbabilities::empty().render_histogram(&symbols, 12), "");
+}
+
+#[test]
+fn two_d6_between() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[d6(), d6()], &policy).unwrap();
+
+    let between = results.get_odds(&[RollTarget::between(3, 5, &symbols)]);
+    let combined = results.get_odds(&[RollTarget::at_least_n_of(3, &symbols), RollTarget::at_most_n_of(5, &symbols)]);
+    assert_eq!(between, 9.0/36.0);
+    assert_eq!(between, combined);
+    assert_eq!(results.get_odds(&[RollTarget::between(7, 7, &symbols)]), 6.0/36.0);
+    assert_eq!(results.get_odds(&[RollTarget::between(5, 3, &symbols)]), 0.0);
+}
+
+#[test]
+fn two_custom_d4_get_odds_any_overlapping() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let swords = vec![ sword.clone() ];
+    let shields = vec![ shield.clone() ];
+    let both_symbols = vec![ sword.clone(), shield.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ sword.clone(), shield.clone() ]),
+        DieSide::new(vec![ shield.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&both_symbols);
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4], &policy).unwrap();
+
+    let two_swords = RollTarget::at_least_n_of(2, &swords);
+    let one_shield = RollTarget::at_least_n_of(1, &shields);
+    let either = results.get_odds_any(&[ two_swords, one_shield ]);
+    let both = results.get_odds(&[ two_swords, one_shield ]);
+
+    assert_eq!(results.get_odds(&[ two_swords ]), 4.0/16.0);
+    assert_eq!(results.get_odds(&[ one_shield ]), 12.0/16.0);
+    assert_eq!(both, 3.0/16.0);
+    assert_eq!(either, 13.0/16.0);
+    assert_eq!(results.get_odds_any(&[]), 0.0);
+}
+
+#[test]
+fn two_custom_d4_negated_targets() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let swords = vec![ sword.clone() ];
+    let shields = vec![ shield.clone() ];
+    let both_symbols = vec![ sword.clone(), shield.clone() ];
+    let sides = vec![
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ sword.clone(), shield.clone() ]),
+        DieSide::new(vec![ shield.clone() ]),
+        DieSide::new(vec![ ])
+    ];
+    let custom_d4 = Die::new(sides).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&both_symbols);
+    let results = RollProbabilities::new(&[custom_d4.clone(), custom_d4], &policy).unwrap();
+
+    let one_sword = RollTarget::exactly_n_of(1, &swords);
+    let two_shields = RollTarget::exactly_n_of(2, &shields);
+
+    assert_eq!(results.get_odds(&[ one_sword, two_shields ]), 2.0/16.0);
+    assert_eq!(results.get_odds(&[ one_sword, two_shields.negate() ]), 6.0/16.0);
+    assert_eq!(results.get_odds(&[ one_sword.negate() ]), 8.0/16.0);
+    assert_eq!(results.get_odds(&[ two_shields.negate().negate() ]), results.get_odds(&[ two_shields ]));
+}
+
+#[test]
+fn combine_matches_single_pool() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let symbols = vec![ sword.clone(), shield.clone() ];
+    let attack_die = Die::new(vec![
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ sword.clone(), sword.clone() ]),
+        DieSide::new(vec![ ])
+    ]).unwrap();
+    let bonus_die = Die::new_weighted(vec![
+        (DieSide::new(vec![ shield.clone() ]), 1),
+        (DieSide::new(vec![ sword.clone(), shield.clone() ]), 2)
+    ]).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let all_at_once = RollProbabilities::new(&[attack_die.clone(), attack_die.clone(), bonus_die.clone()], &policy).unwrap();
+    let attack = RollProbabilities::new(&[attack_die.clone(), attack_die], &policy).unwrap();
+    let bonus = RollProbabilities::new(&[bonus_die], &policy).unwrap();
+
+    let combined = attack.combine(&bonus).unwrap();
+
+    let swords = vec![ sword ];
+    let shields = vec![ shield ];
+    for n in 0..=5 {
+        for m in 0..=1 {
+            let targets = [ RollTarget::exactly_n_of(n, &swords), RollTarget::exactly_n_of(m, &shields) ];
+            assert!((combined.get_odds(&targets) - all_at_once.get_odds(&targets)).abs() < 1e-12);
+        }
+    }
+    assert_eq!(combined.get_odds(&[ RollTarget::at_least_n_of(0, &swords) ]), 1.0);
+    assert_eq!(attack.combine(&RollProbabilities::empty()).unwrap().get_odds(&[ RollTarget::at_least_n_of(0, &swords) ]), 0.0);
+}
+
+#[test]
+fn combining_too_many_combinations_is_rejected() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    // each roll has just over half the bits of a usize worth of combinations
+    let half = 1usize << (usize::BITS / 2);
+    let loaded = Die::new_weighted(vec![ (DieSide::new(vec![ pip() ]), half), (DieSide::new(vec![ pip(), pip() ]), 1) ]).unwrap();
+    let results = RollProbabilities::new(&[ loaded ], &policy).unwrap();
+    let d4_result = RollProbabilities::new(&[ d4() ], &policy).unwrap();
+
+    assert!(results.combine(&results).is_err());
+    assert_eq!(results.combine(&d4_result).unwrap().total, (half + 1) * 4);
+}
+
+#[test]
+fn identical_dice_match_full_enumeration() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let symbols = vec![ sword.clone(), shield.clone() ];
+    let custom_die = Die::new_weighted(vec![
+        (DieSide::new(vec![ sword.clone() ]), 2),
+        (DieSide::new(vec![ sword.clone(), shield.clone() ]), 1),
+        (DieSide::new(vec![ shield.clone() ]), 3),
+        (DieSide::new(vec![ ]), 1)
+    ]).unwrap();
+    let dice = vec![ custom_die.clone(), standard::d4(), custom_die.clone(), custom_die ];
+    let grouped = RollProbabilities::new(&dice, &RollCollectionPolicy::collect_all(&symbols)).unwrap();
+    let enumerated = RollProbabilities::new(&dice, &RollCollectionPolicy::take_highest_n_of(4, &symbols)).unwrap();
+
+    let swords = vec![ sword ];
+    let shields = vec![ shield ];
+    for n in 0..=6 {
+        for m in 0..=3 {
+            let targets = [ RollTarget::exactly_n_of(n, &swords), RollTarget::exactly_n_of(m, &shields) ];
+            assert!((grouped.get_odds(&targets) - enumerated.get_odds(&targets)).abs() < 1e-12);
+        }
+    }
+    assert_eq!(grouped.get_odds(&[ RollTarget::at_least_n_of(0, &swords) ]), 1.0);
+}
+
+#[test]
+fn ten_d10_collect_all() {
+    let symbols = vec![ standard::pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&vec![ standard::d10(); 10 ], &policy).unwrap();
+
+    assert_eq!(results.get_odds(&[ RollTarget::exactly_n_of(10, &symbols) ]), 1.0 / 10_000_000_000.0);
+    assert_eq!(results.get_odds(&[ RollTarget::exactly_n_of(11, &symbols) ]), 10.0 / 10_000_000_000.0);
+    assert!((results.mean(&symbols) - 55.0).abs() < 1e-9);
+}
+
+#[test]
+fn four_fate_dice() {
+    let symbols = vec![ standard::plus(), standard::minus() ];
+    let pluses = vec![ standard::plus() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&vec![ standard::df(); 4 ], &policy).unwrap();
+
+    test_results_exactly(&results, &pluses, 0, 16.0/81.0);
+    test_results_exactly(&results, &pluses, 4, 1.0/81.0);
+    let totals = results.value_distribution();
+    assert_eq!(totals.len(), 9);
+    assert_eq!(totals[0], (-4, 1.0/81.0));
+    assert!((totals[4].1 - 19.0/81.0).abs() < 1e-12);
+}
+
+#[test]
+fn percentile_pair_is_flat() {
+    let pair = standard::percentile_pair();
+    let symbols: Vec<DieSymbol> = pair.iter().flat_map(|d| d.unique_symbols()).collect();
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&pair, &policy).unwrap();
+
+    let totals = results.value_distribution();
+    assert_eq!(totals.len(), 100);
+    for (i, (total, odds)) in totals.iter().enumerate() {
+        assert_eq!(*total, i as i64);
+        assert_eq!(*odds, 0.01);
+    }
+    let mut read: Vec<i64> = totals.into_iter().map(|(total, _)| standard::percentile_total(total)).collect();
+    read.sort_unstable();
+    assert_eq!(read, (1..=100).collect::<Vec<i64>>());
+}
+
+#[test]
+fn count_successes_pool() {
+    let symbols = vec![ pip() ];
+    let successes = vec![ RollCollectionPolicy::success_symbol() ];
+    let policy = RollCollectionPolicy::count_successes(8, &symbols);
+    let results = RollProbabilities::new(&vec![ d10(); 3 ], &policy).unwrap();
+
+    test_results_exactly(&results, &successes, 0, 0.343);
+    test_results_exactly(&results, &successes, 3, 0.027);
+    assert!((results.get_odds(&[ RollTarget::at_least_n_of(1, &successes) ]) - 0.657).abs() < 1e-12);
+}
+
+#[test]
+fn count_successes_mixed_dice() {
+    let symbols = vec![ pip() ];
+    let successes = vec![ RollCollectionPolicy::success_symbol() ];
+    let policy = RollCollectionPolicy::count_successes(4, &symbols);
+    let results = RollProbabilities::new(&[ d6(), d10() ], &policy).unwrap();
+
+    test_results_exactly(&results, &successes, 0, 9.0/60.0);
+    test_results_exactly(&results, &successes, 1, 30.0/60.0);
+    test_results_exactly(&results, &successes, 2, 21.0/60.0);
+}
+
+#[test]
+fn count_successes_with_botch_pool() {
+    let botch = DieSymbol::new("Botch").unwrap();
+    let mut sides: Vec<DieSide> = d10().sides().to_vec();
+    sides[0] = DieSide::new(vec![ pip(), botch.clone() ]);
+    let botch_d10 = Die::new(sides).unwrap();
+    let symbols = vec![ pip() ];
+    let botches = vec![ botch ];
+    let successes = vec![ RollCollectionPolicy::success_symbol() ];
+    let policy = RollCollectionPolicy::count_successes_with_botch(8, &botches, &symbols);
+    let results = RollProbabilities::new(&vec![ botch_d10; 5 ], &policy).unwrap();
+
+    // five successes need every die to show 8 or more
+    test_results_exactly(&results, &successes, 5, 243.0/100_000.0);
+    // four net successes: four successes and a plain failure
+    test_results_exactly(&results, &successes, 4, (5.0 * 81.0 * 6.0)/100_000.0);
+    // botches outnumbering successes collect the botch symbol rather than nothing
+    let botched = vec![ RollCollectionPolicy::botch_symbol() ];
+    let botch_odds = 12_736.0/100_000.0;
+    assert!((results.get_odds(&[ RollTarget::at_least_n_of(1, &botched) ]) - botch_odds).abs() < 1e-12);
+    test_results_exactly(&results, &botched, 5, 1.0/100_000.0);
+    // net zero needs as many successes as botches
+    let net_zero = [ RollTarget::exactly_n_of(0, &successes), RollTarget::exactly_n_of(0, &botched) ];
+    assert!((results.get_odds(&net_zero) - 22_356.0/100_000.0).abs() < 1e-12);
+    let total: f64 = (1..=5).map(|n| results.get_odds(&[ RollTarget::exactly_n_of(n, &successes) ])).sum();
+    assert!((total + botch_odds + 22_356.0/100_000.0 - 1.0).abs() < 1e-12);
+    assert_eq!(results.value_distribution()[0], (-5, 1.0/100_000.0));
+}
+
+#[test]
+fn merged_counters_hash_like_single_counter() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash_of = |counter: &ItemCounter<DieSymbol>| {
+        let mut hasher = DefaultHasher::new();
+        counter.hash(&mut hasher);
+        hasher.finish()
+    };
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let mut first = ItemCounter::new();
+    first.add(&sword);
+    first.add(&shield);
+    let mut second = ItemCounter::new();
+    second.add_amount(&sword, 2);
+    let mut single = ItemCounter::new();
+    single.add(&shield);
+    single.add_amount(&sword, 3);
+
+    let merged = first.merge(&second);
+
+    assert_eq!(merged, single);
+    assert_eq!(merged, second.merge(&first));
+    assert_eq!(hash_of(&merged), hash_of(&single));
+    assert_eq!(merged.iter().map(|(_, count)| count).sum::<usize>(), merged.total_count());
+}
+
+#[test]
+fn counter_from_iter_matches_manual_adds() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let symbols = vec![ sword.clone(), shield.clone(), sword.clone(), sword.clone() ];
+    let mut manual = ItemCounter::new();
+    for symbol in symbols.iter() {
+        manual.add(symbol);
+    }
+
+    let collected: ItemCounter<DieSymbol> = symbols.iter().cloned().collect();
+
+    assert_eq!(collected, manual);
+    assert_eq!(ItemCounter::from_slice(&symbols), manual);
+    assert_eq!(collected.get_count(&sword), 3);
+    assert_eq!(collected.get_count(&shield), 1);
+    assert_eq!(ItemCounter::<DieSymbol>::from_slice(&[]), ItemCounter::new());
+}
+
+#[test]
+fn counter_hash_ignores_insertion_order() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    #[derive(Hash, PartialEq, Eq, Clone, Debug)]
+    struct Unordered(&'static str);
+
+    let hash_of = |counter: &ItemCounter<Unordered>| {
+        let mut hasher = DefaultHasher::new();
+        counter.hash(&mut hasher);
+        hasher.finish()
+    };
+    let names = [ "Sword", "Shield", "Blank", "Pip", "Crown", "Skull", "Star", "Moon" ];
+    let mut forward = ItemCounter::new();
+    for (i, name) in names.iter().enumerate() {
+        forward.add_amount(&Unordered(name), i + 1);
+    }
+    let mut backward = ItemCounter::new();
+    for (i, name) in names.iter().enumerate().rev() {
+        for _ in 0..=i {
+            backward.add(&Unordered(name));
+        }
+    }
+
+    assert_eq!(forward, backward);
+    assert_eq!(hash_of(&forward), hash_of(&backward));
+    backward.remove(&Unordered("Moon"));
+    assert_ne!(hash_of(&forward), hash_of(&backward));
+}
+
+#[test]
+fn best_of_rounds() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let d8_result = RollProbabilities::new(&[ d8() ], &policy).unwrap();
+    let d4_result = RollProbabilities::new(&[ d4() ], &policy).unwrap();
+
+    let single = d8_result.best_of(&d4_result, 1).unwrap();
+    let single_compare = d8_result.roll_against(&d4_result).unwrap();
+    assert_eq!(single.win_odds(), single_compare.win_odds());
+    assert_eq!(single.tie_odds(), single_compare.tie_odds());
+
+    let best_of_three = d8_result.best_of(&d4_result, 3).unwrap();
+    assert_eq!(best_of_three.win_odds(), 3278.0 / 4096.0);
+    assert_eq!(best_of_three.tie_odds(), 404.0 / 4096.0);
+    assert_eq!(best_of_three.loss_odds(), 414.0 / 4096.0);
+
+    let reversed = d4_result.best_of(&d8_result, 3).unwrap();
+    assert_eq!(reversed.win_odds(), best_of_three.loss_odds());
+
+    assert_eq!(d8_result.best_of(&d4_result, 0).unwrap().tie_odds(), 1.0);
+    assert_eq!(d8_result.best_of(&RollProbabilities::empty(), 3).unwrap().win_odds(), 0.0);
+
+    // 1296^7 combinations of two d6 rounds don't fit in a usize
+    let two_d6 = RollProbabilities::new(&[ d6(), d6() ], &policy).unwrap();
+    assert!(two_d6.best_of(&two_d6, 7).is_err());
+    assert!(two_d6.best_of(&two_d6, usize::MAX).is_err());
+    assert!(two_d6.best_of(&two_d6, 5).is_ok());
+    let always_wins = d8_result.best_of(&d8_result.clamp(&symbols, 0), usize::MAX).unwrap();
+    assert_eq!(always_wins.win_odds(), 1.0);
+}
+
+#[test]
+fn compare_counts_and_margins() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let two_d4 = RollProbabilities::new(&[ d4(), d4() ], &policy).unwrap();
+    let d6_result = RollProbabilities::new(&[ d6() ], &policy).unwrap();
+
+    let compare = two_d4.roll_against(&d6_result).unwrap();
+    assert_eq!(compare.total(), 96);
+    assert_eq!(compare.wins() + compare.ties() + compare.losses(), compare.total());
+
+    let margins = two_d4.margin_distribution(&d6_result, &symbols).unwrap();
+    assert_eq!(margins.first().unwrap().0, -4);
+    assert_eq!(margins.last().unwrap().0, 7);
+    let win_odds: f64 = margins.iter().filter(|(m, _)| *m > 0).map(|(_, p)| p).sum();
+    let tie_odds: f64 = margins.iter().filter(|(m, _)| *m == 0).map(|(_, p)| p).sum();
+    assert!((win_odds - compare.win_odds()).abs() < 1e-12);
+    assert!((tie_odds - compare.tie_odds()).abs() < 1e-12);
+    assert!(two_d4.margin_distribution(&RollProbabilities::empty(), &symbols).unwrap().is_empty());
+}
+
+#[test]
+fn counted_dice_match_repeated_dice() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let counted = RollProbabilities::new_counted(&[ (d6(), 3), (d4(), 2) ], &policy).unwrap();
+    let repeated = RollProbabilities::new(&[ d6(), d6(), d6(), d4(), d4() ], &policy).unwrap();
+
+    assert_eq!(counted.distribution(&symbols), repeated.distribution(&symbols));
+    assert!(RollProbabilities::new_counted(&[], &policy).is_err());
+    assert!(RollProbabilities::new_counted(&[ (d6(), 2), (d4(), 0) ], &policy).is_err());
+}
+
+#[test]
+fn take_highest_by_value() {
+    let symbols = vec![ pip() ];
+    let by_count = RollProbabilities::new(&[ d6(), d6(), d6() ], &RollCollectionPolicy::take_highest_n_of(2, &symbols)).unwrap();
+    let by_value = RollProbabilities::new(&[ d6(), d6(), d6() ], &RollCollectionPolicy::take_highest_n_by_value(2, &symbols)).unwrap();
+    assert_eq!(by_count.distribution(&symbols), by_value.distribution(&symbols));
+
+    // a single heavy symbol outweighs several light ones
+    let gem = DieSymbol::new_valued("Gem", 5).unwrap();
+    let coin = DieSymbol::new("Coin").unwrap();
+    let treasure_die = Die::new(vec![
+        DieSide::new(vec![ gem.clone() ]),
+        DieSide::new(vec![ coin.clone(), coin.clone() ])
+    ]).unwrap();
+    let treasure = vec![ gem, coin ];
+    let gems = vec![ treasure[0].clone() ];
+    let results = RollProbabilities::new(&[ treasure_die.clone(), treasure_die ], &RollCollectionPolicy::take_highest_n_by_value(1, &treasure)).unwrap();
+    test_results_exactly(&results, &gems, 1, 0.75);
+    assert_eq!(results.value_distribution(), vec![ (2, 0.25), (5, 0.75) ]);
+}
+
+#[test]
+fn collect_above_threshold() {
+    let symbols = vec![ pip() ];
+    let results = RollProbabilities::new(&[ d6(), d6() ], &RollCollectionPolicy::collect_above(4, &symbols)).unwrap();
+
+    // both dice show 3 or less
+    test_results_exactly(&results, &symbols, 0, 9.0/36.0);
+    // one die shows 4 and the other 3 or less, in either order
+    test_results_exactly(&results, &symbols, 4, 6.0/36.0);
+    // both dice show 4
+    test_results_exactly(&results, &symbols, 8, 1.0/36.0);
+    test_results_exactly(&results, &symbols, 9, 2.0/36.0);
+    test_results_exactly(&results, &symbols, 12, 1.0/36.0);
+    test_results_exactly(&results, &symbols, 3, 0.0);
+}
+
+#[test]
+fn d20_with_advantage_die() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let advantage = RollProbabilities::new(&[ d20().with_advantage(&symbols) ], &policy).unwrap();
+    let disadvantage = RollProbabilities::new(&[ d20().with_disadvantage(&symbols) ], &policy).unwrap();
+    let rolled_twice = RollProbabilities::new(&[ d20() ], &policy).unwrap().with_advantage(&symbols).unwrap();
+
+    for n in 1..=20 {
+        let at_most = RollTarget::at_most_n_of(n, &symbols);
+        assert!((advantage.get_odds(&[ at_most ]) - (n * n) as f64 / 400.0).abs() < 1e-12);
+        assert_eq!(advantage.get_odds(&[ at_most ]), rolled_twice.get_odds(&[ at_most ]));
+        let at_least = RollTarget::at_least_n_of(21 - n, &symbols);
+        assert!((disadvantage.get_odds(&[ at_least ]) - (n * n) as f64 / 400.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn custom_die_with_advantage_keeps_first_on_ties() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let swords = vec![ sword.clone() ];
+    let die = Die::new(vec![
+        DieSide::new(vec![ shield.clone() ]),
+        DieSide::new(vec![ ]),
+        DieSide::new(vec![ sword.clone() ])
+    ]).unwrap();
+
+    let advantage = die.with_advantage(&swords);
+    let disadvantage = die.with_disadvantage(&swords);
+
+    assert_eq!(advantage.weights(), &[ 2, 2, 5 ]);
+    assert_eq!(disadvantage.weights(), &[ 4, 4, 1 ]);
+}
+
+#[test]
+fn clamp_and_floor_keep_total() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let both_symbols = vec![ sword.clone(), shield.clone() ];
+    let swords = vec![ sword.clone() ];
+    let shields = vec![ shield.clone() ];
+    let die = Die::new(vec![
+        DieSide::new(vec![ sword.clone(), shield.clone() ]),
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ ])
+    ]).unwrap();
+    let results = RollProbabilities::new(&[ die.clone(), die ], &RollCollectionPolicy::collect_all(&both_symbols)).unwrap();
+
+    let clamped = results.clamp(&both_symbols, 2);
+    assert_eq!(clamped.distribution(&both_symbols), vec![ (0, 1.0/9.0), (1, 2.0/9.0), (2, 6.0/9.0) ]);
+    // shields are removed before swords
+    assert_eq!(clamped.get_odds(&[ RollTarget::exactly_n_of(2, &swords) ]), 4.0/9.0);
+    assert_eq!(clamped.get_odds(&[ RollTarget::at_least_n_of(0, &shields) ]), 1.0);
+
+    let floored = results.floor(&both_symbols, 2);
+    assert_eq!(floored.distribution(&both_symbols), vec![ (2, 6.0/9.0), (3, 2.0/9.0), (4, 1.0/9.0) ]);
+    assert_eq!(floored.get_odds(&[ RollTarget::exactly_n_of(0, &shields) ]), results.get_odds(&[ RollTarget::exactly_n_of(0, &shields) ]));
+    assert_eq!(results.floor(&[], 3).distribution(&both_symbols), results.distribution(&both_symbols));
+}
+
+#[test]
+fn count_range_and_mode() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let three_d6 = RollProbabilities::new(&[ d6(), d6(), d6() ], &policy).unwrap();
+    assert_eq!(three_d6.min_count(&symbols), 3);
+    assert_eq!(three_d6.max_count(&symbols), 18);
+    // 10 and 11 are equally likely
+    assert_eq!(three_d6.mode(&symbols), 10);
+
+    let d6_result = RollProbabilities::new(&[ d6() ], &policy).unwrap();
+    assert_eq!(d6_result.mode(&symbols), 1);
+
+    let empty = RollProbabilities::empty();
+    assert_eq!(empty.min_count(&symbols), 0);
+    assert_eq!(empty.max_count(&symbols), 0);
+    assert_eq!(empty.mode(&symbols), 0);
+}
+
+#[test]
+fn odds_of_exact_outcome() {
+    let sword = DieSymbol::new("Sword").unwrap();
+    let shield = DieSymbol::new("Shield").unwrap();
+    let skull = DieSymbol::new("Skull").unwrap();
+    let collected = vec![ sword.clone(), shield.clone() ];
+    let die = Die::new(vec![
+        DieSide::new(vec![ sword.clone() ]),
+        DieSide::new(vec![ sword.clone(), shield.clone() ]),
+        DieSide::new(vec![ skull.clone() ]),
+        DieSide::new(vec![ ])
+    ]).unwrap();
+    let results = RollProbabilities::new(&[ die.clone(), die ], &RollCollectionPolicy::collect_all(&collected)).unwrap();
+
+    // two swords and no shield: a lone sword on both dice
+    assert_eq!(results.odds_of_exact(&[ (sword.clone(), 2) ]), 1.0/16.0);
+    // stacking exactly-targets ignores the shield count
+    let swords = vec![ sword.clone() ];
+    assert_eq!(results.get_odds(&[ RollTarget::exactly_n_of(2, &swords) ]), 4.0/16.0);
+    assert_eq!(results.odds_of_exact(&[ (sword.clone(), 2), (shield.clone(), 0) ]), 1.0/16.0);
+    assert_eq!(results.odds_of_exact(&[ (sword.clone(), 1), (sword.clone(), 1), (shield.clone(), 1) ]), 2.0/16.0);
+    // skulls aren't collected, so they can only be asked for zero times
+    assert_eq!(results.odds_of_exact(&[]), 4.0/16.0);
+    assert_eq!(results.odds_of_exact(&[ (skull.clone(), 0) ]), 4.0/16.0);
+    assert_eq!(results.odds_of_exact(&[ (skull, 1) ]), 0.0);
+    assert_eq!(RollProbabilities::empty().odds_of_exact(&[ (sword, 1) ]), 0.0);
+}
+
+#[test]
+fn distribution_map_sums_to_one() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let results = RollProbabilities::new(&[ d6(), d8(), d10() ], &policy).unwrap();
+
+    let distribution = results.to_distribution(&symbols);
+
+    assert_eq!(distribution.len(), 22);
+    assert_eq!(distribution.keys().copied().collect::<Vec<_>>(), (3..=24).collect::<Vec<_>>());
+    assert!((distribution.values().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!(RollProbabilities::empty().to_distribution(&symbols).is_empty());
+}
+
+#[test]
+fn overflowing_pools_are_rejected() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    // 10^19 combinations still fit, 10^20 do not
+    assert!(RollProbabilities::new(&vec![ d10(); 20 ], &policy).is_err());
+    assert!(RollProbabilities::new_counted(&[ (d10(), 20) ], &policy).is_err());
+    assert!(RollProbabilities::new_totals_only(&vec![ d10(); 20 ], &policy, &symbols).is_err());
+
+    // two dice each weighing half the bits of a usize are just too many to count
+    let half = 1usize << (usize::BITS / 2);
+    let loaded = |weight: usize| Die::new_weighted(vec![ (DieSide::new(vec![ pip() ]), weight), (DieSide::new(vec![ pip(), pip() ]), 1) ]).unwrap();
+    let just_fits = loaded(half - 2);
+    let overflows = loaded(half - 1);
+    let fits = RollProbabilities::new(&[ just_fits.clone(), just_fits ], &policy).unwrap();
+    assert_eq!(fits.get_odds(&[ RollTarget::exactly_n_of(4, &symbols) ]), 1.0 / ((half - 1) as f64 * (half - 1) as f64));
+    assert!(RollProbabilities::new(&[ overflows.clone(), overflows ], &policy).is_err());
+}
+
+#[test]
+fn policy_apply_matches_probabilities() {
+    let symbols = vec![ pip() ];
+    let successes = vec![ RollCollectionPolicy::success_symbol() ];
+    let dice = vec![ d4(), d6(), d4() ];
+    let policies = [
+        (RollCollectionPolicy::take_highest_n_of(2, &symbols), &symbols),
+        (RollCollectionPolicy::remove_lowest_n_of(1, &symbols), &symbols),
+        (RollCollectionPolicy::count_successes(3, &symbols), &successes) ];
+
+    for (policy, counted) in policies.iter() {
+        let results = RollProbabilities::new(&dice, policy).unwrap();
+        let mut counts = [ 0usize; 11 ];
+        for a in dice[0].sides() {
+            for b in dice[1].sides() {
+                for c in dice[2].sides() {
+                    counts[policy.apply(&[ a, b, c ]).len()] += 1;
+                }
+            }
+        }
+
+        for (n, count) in counts.iter().enumerate() {
+            let odds = results.get_odds(&[ RollTarget::exactly_n_of(n, counted) ]);
+            assert!((odds - *count as f64 / 96.0).abs() < 1e-12);
+        }
+    }
+
+    let d6 = d6();
+    let roll = vec![ &d6.sides()[1], &d6.sides()[4], &d6.sides()[2] ];
+    assert_eq!(RollCollectionPolicy::take_highest_n_of(1, &symbols).apply(&roll), vec![ pip(); 5 ]);
+    assert_eq!(RollCollectionPolicy::count_successes(3, &symbols).apply(&roll), vec![ RollCollectionPolicy::success_symbol(); 2 ]);
+}
+
+#[test]
+fn set_and_distinct_targets() {
+    let faces: Vec<DieSymbol> = (1..=6).map(|n| DieSymbol::new_valued(n.to_string(), n).unwrap()).collect();
+    let face_die = Die::new(faces.iter().map(|f| DieSide::new(vec![ f.clone() ])).collect()).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&faces);
+    let results = RollProbabilities::new(&vec![ face_die; 3 ], &policy).unwrap();
+
+    assert!((results.get_odds(&[ RollTarget::has_set_of(3, &faces) ]) - 6.0 / 216.0).abs() < 1e-12);
+    assert!((results.get_odds(&[ RollTarget::has_set_of(2, &faces) ]) - 96.0 / 216.0).abs() < 1e-12);
+    assert!((results.get_odds(&[ RollTarget::has_set_of(2, &faces[..1]) ]) - 16.0 / 216.0).abs() < 1e-12);
+    assert_eq!(results.get_odds(&[ RollTarget::has_set_of(4, &faces) ]), 0.0);
+    assert!((results.get_odds(&[ RollTarget::has_n_distinct(3, &faces) ]) - 120.0 / 216.0).abs() < 1e-12);
+    assert!((results.get_odds(&[ RollTarget::has_n_distinct(3, &faces).negate() ]) - 96.0 / 216.0).abs() < 1e-12);
+    assert_eq!(results.get_odds(&[ RollTarget::has_n_distinct(1, &faces) ]), 1.0);
+    assert_eq!(results.get_odds(&[ RollTarget::has_n_distinct(4, &faces) ]), 0.0);
+}
+
+#[test]
+fn odds_of_runs() {
+    let faces: Vec<DieSymbol> = (1..=6).map(|n| DieSymbol::new_valued(n.to_string(), n).unwrap()).collect();
+    let face_die = Die::new(faces.iter().map(|f| DieSide::new(vec![ f.clone() ])).collect()).unwrap();
+    let policy = RollCollectionPolicy::collect_all(&faces);
+    let five_dice = RollProbabilities::new(&vec![ face_die.clone(); 5 ], &policy).unwrap();
+
+    assert!((five_dice.odds_of_run(5) - 240.0 / 7776.0).abs() < 1e-12);
+    assert!((five_dice.odds_of_run(4) - 1200.0 / 7776.0).abs() < 1e-12);
+    assert_eq!(five_dice.odds_of_run(1), 1.0);
+    assert_eq!(five_dice.odds_of_run(0), 1.0);
+    assert_eq!(five_dice.odds_of_run(6), 0.0);
+    assert_eq!(RollProbabilities::empty().odds_of_run(0), 0.0);
+
+    // a gap ends the run, so 1 2 4 5 is only a run of 2
+    let gapped = Die::new(vec![ 
+        DieSide::new(vec![ faces[0].clone(), faces[1].clone(), faces[1].clone(), faces[3].clone(), faces[4].clone() ]),
+        DieSide::new(vec![ faces[0].clone(), faces[1].clone(), faces[2].clone() ]) ]).unwrap();
+    let gapped_results = RollProbabilities::new(&[ gapped ], &policy).unwrap();
+    assert_eq!(gapped_results.odds_of_run(2), 1.0);
+    assert_eq!(gapped_results.odds_of_run(3), 0.5);
+}
+
+#[test]
+fn owned_targets_outlive_their_symbols() {
+    let targets: Vec<OwnedRollTarget> = {
+        let symbols = vec![ pip() ];
+        let weights = vec![ (pip(), 2) ];
+        vec![
+            RollTarget::at_least_n_of(3, &symbols).into_owned(),
+            RollTarget::weighted_at_least(10, &weights).negate().into_owned() ]
+    };
+    let symbols = vec![ pip() ];
+    let results = RollProbabilities::new(&[ d6() ], &RollCollectionPolicy::collect_all(&symbols)).unwrap();
+    let borrowed: Vec<RollTarget> = targets.iter().map(|t| t.as_target()).collect();
+
+    assert_eq!(targets[0].as_target(), RollTarget::at_least_n_of(3, &symbols));
+    assert_ne!(targets[0].as_target(), RollTarget::at_least_n_of(3, &symbols).negate());
+    assert_eq!(results.get_odds(&borrowed[..1]), 4.0 / 6.0);
+    assert_eq!(results.get_odds(&borrowed), 2.0 / 6.0);
+
+    let mut stored = std::collections::HashSet::new();
+    stored.insert(targets[0].clone());
+    assert!(stored.contains(&RollTarget::at_least_n_of(3, &symbols).into_owned()));
+}
+
+#[test]
+fn new_rejects_policies_no_die_can_meet() {
+    let symbols = vec![ pip() ];
+    let sword = vec![ DieSymbol::new("Sword").unwrap() ];
+    let sword_die = Die::new(vec![ DieSide::new(sword.clone()), DieSide::new(vec![]) ]).unwrap();
+
+    assert!(RollProbabilities::new(&[ d6(), d4() ], &RollCollectionPolicy::collect_all(&sword)).is_err());
+    assert!(RollProbabilities::new(&[ d6() ], &RollCollectionPolicy::collect_all(&[])).is_err());
+    assert!(RollProbabilities::new(&[ d6(), sword_die.clone() ], &RollCollectionPolicy::collect_all(&sword)).is_ok());
+    assert!(RollProbabilities::new(&[ sword_die ], &RollCollectionPolicy::collect_all(&symbols)).is_err());
+}
+
+#[test]
+fn every_constructor_rejects_what_new_rejects() {
+    let symbols = vec![ pip() ];
+    let sword = vec![ DieSymbol::new("Sword").unwrap() ];
+    let policy = RollCollectionPolicy::collect_all(&sword);
+    let explode = RollModifier::Explode { on: &symbols, max_depth: 1 };
+    let reroll = RollRerollPolicy::once_below(2, &symbols);
+    let error = "none of the dice show any of the policy's symbols";
+
+    assert_eq!(RollProbabilities::new(&[ d6() ], &policy).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_counted(&[ (d6(), 2) ], &policy).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_with_roll_modifier(&[ d6() ], &policy, &explode).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_with_reroll(&[ d6() ], &policy, &reroll).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_totals_only(&[ d6() ], &policy, &sword).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_with_conditional_modifier(&[ d6() ], &policy, &symbols, 1, 1, &sword).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_reroll_failures(&[ d6() ], &symbols, 2, &sword).err().unwrap(), error);
+    assert_eq!(RollProbabilities::new_counted(&[], &policy).err().unwrap(), "must include at least one die");
+    assert_eq!(RollProbabilities::new_totals_only(&[], &policy, &sword).err().unwrap(), "must include at least one die");
+}
+
+#[test]
+fn take_policies_clamp_n_to_pool_size() {
+    let symbols = vec![ pip() ];
+    let dice = vec![ d4(), d6(), d4() ];
+    let all = RollProbabilities::new(&dice, &RollCollectionPolicy::collect_all(&symbols)).unwrap();
+    let policies = [
+        RollCollectionPolicy::take_highest_n_of(5, &symbols),
+        RollCollectionPolicy::take_highest_distinct_n(5, &symbols),
+        RollCollectionPolicy::take_highest_n_by_value(5, &symbols),
+        RollCollectionPolicy::take_lowest_n_of(5, &symbols),
+        RollCollectionPolicy::take_lowest_n_of(3, &symbols) ];
+
+    for policy in policies.iter() {
+        let results = RollProbabilities::new(&dice, policy).unwrap();
+        assert_eq!(results.total, all.total);
+        for n in 3..=14 {
+            test_results_exactly(&results, &symbols, n, all.get_odds(&[ RollTarget::exactly_n_of(n, &symbols) ]));
+        }
+    }
+
+    let d6 = d6();
+    let roll = vec![ &d6.sides()[1], &d6.sides()[4] ];
+    assert_eq!(RollCollectionPolicy::take_lowest_n_of(usize::MAX, &symbols).apply(&roll).len(), 7);
+}
+
+#[test]
+fn remove_policies_empty_when_n_covers_pool() {
+    let symbols = vec![ pip() ];
+    let dice = vec![ d4(), d6() ];
+
+    for policy in [ RollCollectionPolicy::remove_highest_n_of(3, &symbols), RollCollectionPolicy::remove_lowest_n_of(3, &symbols),
+            RollCollectionPolicy::remove_highest_n_of(2, &symbols), RollCollectionPolicy::remove_lowest_n_of(2, &symbols) ].iter() {
+        let results = RollProbabilities::new(&dice, policy).unwrap();
+        assert_eq!(results.total, 24);
+        test_results_exactly(&results, &symbols, 0, 1.0);
+    }
+
+    let d6 = d6();
+    let roll = vec![ &d6.sides()[1], &d6.sides()[4] ];
+    assert!(RollCollectionPolicy::remove_highest_n_of(3, &symbols).apply(&roll).is_empty());
+    assert!(RollCollectionPolicy::remove_lowest_n_of(usize::MAX, &symbols).apply(&roll).is_empty());
+    assert_eq!(RollCollectionPolicy::remove_lowest_n_of(1, &symbols).apply(&roll).len(), 5);
+}
+
+#[test]
+fn advantage_on_too_many_combinations_is_rejected() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let pool = RollProbabilities::new(&vec![ d10(); 10 ], &policy).unwrap();
+
+    assert_eq!(pool.with_advantage(&symbols).err().unwrap(), "too many combinations to count without overflowing");
+    assert!(pool.with_disadvantage(&symbols).is_err());
+}
+
+#[test]
+fn comparing_too_many_combinations_is_rejected() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let pool = RollProbabilities::new(&vec![ d10(); 10 ], &policy).unwrap();
+
+    assert_eq!(pool.roll_against(&pool).err().unwrap(), "too many combinations to count without overflowing");
+    assert!(pool.roll_against_symbols(&pool, &symbols).is_err());
+    assert!(pool.best_of(&pool, 10).is_err());
+}
+
+#[test]
+fn margins_of_too_many_combinations_are_rejected() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let pool = RollProbabilities::new(&vec![ d10(); 10 ], &policy).unwrap();
+
+    assert_eq!(pool.margin_distribution(&pool, &symbols).unwrap_err(), "too many combinations to count without overflowing");
+    assert!(pool.margin_variance(&pool, &symbols).is_err());
+}
+
+#[test]
+fn pool_distribution_rejects_too_many_combinations() {
+    let symbols = vec![ pip() ];
+    let mut pool = PoolDistribution::new();
+    for _ in 0..19 {
+        pool.add_die(&d10(), &symbols).unwrap();
+    }
+    let before = pool.odds(&[ RollTarget::at_least_n_of(100, &symbols) ]);
+
+    assert_eq!(pool.add_die(&d10(), &symbols).unwrap_err(), "too many combinations to count without overflowing");
+    assert_eq!(pool.probabilities.total, 10usize.pow(19));
+    assert_eq!(pool.odds(&[ RollTarget::at_least_n_of(100, &symbols) ]), before);
+}
+
+#[test]
+fn totals_only_groups_identical_dice() {
+    let symbols = vec![ pip() ];
+    let policy = RollCollectionPolicy::collect_all(&symbols);
+    let dice = vec![ d6(); 20 ];
+    let totals = RollProbabilities::new_totals_only(&dice, &policy, &symbols).unwrap();
+    let full = RollProbabilities::new(&dice, &policy).unwrap();
+
+    assert_eq!(totals.total, full.total);
+    assert_eq!(totals.distribution(&symbols), full.distribution(&symbols));
+    assert_eq!(totals.occurrences.len(), 101);
+    assert!(totals.outcomes().all(|(outcome, _)| outcome.symbol_count(&pip()) == 0));
+    assert!(RollProbabilities::new_totals_only(&vec![ d6(); 30 ], &policy, &symbols).is_err());
+}
+
+#[test]
+fn outcome_is_a_rolled_pool() {
+    let outcome: Outcome = RolledPool::new(vec![ d6().sides()[2].clone() ]);
+
+    assert_eq!(outcome.count_of(&[ pip() ]), 3);
 }
\ No newline at end of file